@@ -0,0 +1,56 @@
+## Feed
+
+feed-title = { $names } - Alle Mitwirkungen
+feed-title-others = { $names } und { $others } { $others ->
+        [one] weitere Person
+       *[other] weitere Personen
+    } - Alle Mitwirkungen
+feed-description = Alle Mitwirkungen von { $names }
+
+## Items
+
+people = Personen
+character = Rolle
+department = Abteilung
+job = Tätigkeit
+genres = Genres
+language = Sprache
+release-date = Erscheinungsdatum
+tba = Noch offen
+release-date-changed = Erscheinungsdatum geändert: { $previous } → { $current }
+title-changed = Titel geändert: { $previous } → { $current }
+
+## Media types
+
+media-type-movie = Film
+media-type-tv = Serie
+
+## Genres, identified by their TMDB genre id
+
+genre-12 = Abenteuer
+genre-14 = Fantasy
+genre-16 = Animation
+genre-18 = Drama
+genre-27 = Horror
+genre-28 = Action
+genre-35 = Komödie
+genre-36 = Historie
+genre-37 = Western
+genre-53 = Thriller
+genre-80 = Krimi
+genre-99 = Dokumentarfilm
+genre-878 = Science Fiction
+genre-9648 = Mystery
+genre-10402 = Musik
+genre-10749 = Liebesfilm
+genre-10751 = Familie
+genre-10752 = Kriegsfilm
+genre-10759 = Action & Adventure
+genre-10762 = Kids
+genre-10763 = News
+genre-10764 = Reality
+genre-10765 = Sci-Fi & Fantasy
+genre-10766 = Soap
+genre-10767 = Talk
+genre-10768 = War & Politics
+genre-10770 = TV-Film
@@ -0,0 +1,56 @@
+## Feed
+
+feed-title = { $names } - Combined Credits
+feed-title-others = { $names } and { $others } { $others ->
+        [one] other
+       *[other] others
+    } - Combined Credits
+feed-description = Combined credits of { $names }
+
+## Items
+
+people = People
+character = Character
+department = Department
+job = Job
+genres = Genres
+language = Language
+release-date = Release Date
+tba = TBA
+release-date-changed = Release date changed: { $previous } → { $current }
+title-changed = Title changed: { $previous } → { $current }
+
+## Media types
+
+media-type-movie = Movie
+media-type-tv = TV
+
+## Genres, identified by their TMDB genre id
+
+genre-12 = Adventure
+genre-14 = Fantasy
+genre-16 = Animation
+genre-18 = Drama
+genre-27 = Horror
+genre-28 = Action
+genre-35 = Comedy
+genre-36 = History
+genre-37 = Western
+genre-53 = Thriller
+genre-80 = Crime
+genre-99 = Documentary
+genre-878 = Science Fiction
+genre-9648 = Mystery
+genre-10402 = Music
+genre-10749 = Romance
+genre-10751 = Family
+genre-10752 = War
+genre-10759 = Action & Adventure
+genre-10762 = Kids
+genre-10763 = News
+genre-10764 = Reality
+genre-10765 = Sci-Fi & Fantasy
+genre-10766 = Soap
+genre-10767 = Talk
+genre-10768 = War & Politics
+genre-10770 = TV Movie
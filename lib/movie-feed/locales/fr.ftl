@@ -0,0 +1,56 @@
+## Feed
+
+feed-title = { $names } - Filmographie complète
+feed-title-others = { $names } et { $others } { $others ->
+        [one] autre
+       *[other] autres
+    } - Filmographie complète
+feed-description = Filmographie complète de { $names }
+
+## Items
+
+people = Personnes
+character = Personnage
+department = Département
+job = Poste
+genres = Genres
+language = Langue
+release-date = Date de sortie
+tba = À venir
+release-date-changed = Date de sortie modifiée : { $previous } → { $current }
+title-changed = Titre modifié : { $previous } → { $current }
+
+## Media types
+
+media-type-movie = Film
+media-type-tv = Série
+
+## Genres, identified by their TMDB genre id
+
+genre-12 = Aventure
+genre-14 = Fantastique
+genre-16 = Animation
+genre-18 = Drame
+genre-27 = Horreur
+genre-28 = Action
+genre-35 = Comédie
+genre-36 = Histoire
+genre-37 = Western
+genre-53 = Thriller
+genre-80 = Crime
+genre-99 = Documentaire
+genre-878 = Science-Fiction
+genre-9648 = Mystère
+genre-10402 = Musique
+genre-10749 = Romance
+genre-10751 = Familial
+genre-10752 = Guerre
+genre-10759 = Action & Aventure
+genre-10762 = Kids
+genre-10763 = News
+genre-10764 = Reality
+genre-10765 = Science-Fiction & Fantastique
+genre-10766 = Soap
+genre-10767 = Talk
+genre-10768 = Guerre & Politique
+genre-10770 = Téléfilm
@@ -1,10 +1,15 @@
+use crate::api::auth::Auth;
+use crate::api::conditional::Validators;
+use crate::api::rate_limit::RateLimiter;
 use crate::api::routes::routes;
-use crate::config::Config;
+use crate::config::{Config, WatchlistConfig};
+use crate::store::Store;
 use axum::extract::{FromRequestParts, Request};
 use axum::http::HeaderName;
 use axum::middleware::Next;
 use axum::{middleware, serve};
 use axum_client_ip::ClientIp;
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -18,13 +23,23 @@ use tower_http::request_id::{
 };
 use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::TraceLayer;
-use tracing::{Span, debug_span, info};
-
+use tracing::{Span, debug_span, info, warn};
+use url::Url;
+
+mod atom;
+mod auth;
+mod conditional;
+pub(crate) mod feed;
+mod ical;
+mod json_feed;
 mod process_result;
+mod rate_limit;
 mod routes;
 mod rss;
 
 const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+/// The time allowed for each request, after which 408 Request Timeout is returned
+pub(crate) const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[derive(Error, Debug)]
 pub(crate) enum ApiError {
@@ -34,11 +49,29 @@ pub(crate) enum ApiError {
 
 pub(crate) struct ApiState {
     tmdb: Tmdb,
+    lists: HashMap<String, WatchlistConfig>,
+    validators: Validators,
+    store: Arc<Store>,
+    public_url: Option<Url>,
 }
 
 impl ApiState {
-    pub(crate) fn new(tmdb: Tmdb) -> Self {
-        Self { tmdb }
+    pub(crate) fn new(tmdb: Tmdb, lists: HashMap<String, WatchlistConfig>, store: Store) -> Self {
+        Self {
+            tmdb,
+            lists,
+            validators: Validators::default(),
+            store: Arc::new(store),
+            public_url: None,
+        }
+    }
+
+    /// Sets the url through which clients reach the api, see [`ApiConfig::public_url`]
+    ///
+    /// [`ApiConfig::public_url`]: crate::config::ApiConfig::public_url
+    pub(crate) fn with_public_url(mut self, public_url: Option<Url>) -> Self {
+        self.public_url = public_url;
+        self
     }
 }
 
@@ -50,8 +83,18 @@ pub(crate) async fn start_api_server(
 
     let listener = TcpListener::bind(addr).await?;
 
+    let auth = Auth::new(config.api.users.clone());
+
+    if !auth.is_enabled() {
+        warn!("no users are configured, client authentication is disabled");
+    }
+
     let router = routes()
         .layer(AddExtensionLayer::new(Arc::new(api_state)))
+        .layer(AddExtensionLayer::new(Arc::new(auth)))
+        .layer(AddExtensionLayer::new(Arc::new(RateLimiter::new(
+            config.api.rate_limit.clone(),
+        ))))
         .layer(middleware::from_fn(async |request: Request, next: Next| {
             let span = Span::current();
 
@@ -86,7 +129,7 @@ pub(crate) async fn start_api_server(
         .layer(config.api.client_ip_source.clone().into_extension())
         .layer(PropagateRequestIdLayer::new(REQUEST_ID_HEADER))
         .layer(SetRequestIdLayer::new(REQUEST_ID_HEADER, MakeRequestUuid))
-        .layer(TimeoutLayer::new(Duration::from_secs(30)));
+        .layer(TimeoutLayer::new(REQUEST_TIMEOUT));
 
     let handle = tokio::spawn(async move {
         serve(
@@ -0,0 +1,64 @@
+use atom_syndication::{Feed, WriteConfig};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+pub(super) struct Atom {
+    feed: Feed,
+}
+
+impl Atom {
+    pub(super) fn new(feed: Feed) -> Self {
+        Self { feed }
+    }
+
+    #[cfg(test)]
+    pub(super) fn feed(&self) -> &Feed {
+        &self.feed
+    }
+}
+
+impl IntoResponse for Atom {
+    fn into_response(self) -> Response {
+        let config = WriteConfig {
+            write_document_declaration: true,
+            indent_size: Some(2),
+        };
+
+        match self.feed.write_with_config(Vec::new(), config) {
+            Ok(bytes) => (
+                [(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("application/atom+xml"),
+                )],
+                bytes,
+            )
+                .into_response(),
+            Err(error) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static(mime::TEXT_PLAIN_UTF_8.as_ref()),
+                )],
+                error.to_string(),
+            )
+                .into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type() {
+        let atom = Atom::new(Feed::default());
+        let response = atom.into_response();
+
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE),
+            Some(HeaderValue::from_static("application/atom+xml")).as_ref()
+        );
+    }
+}
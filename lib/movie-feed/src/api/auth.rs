@@ -0,0 +1,241 @@
+use crate::config::{TokenHash, UserConfig};
+use axum::Extension;
+use axum::extract::Request;
+use axum::http::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+
+const REALM: &str = r#"Basic realm="movie-feed", charset="UTF-8""#;
+
+/// Users permitted to access the API
+pub(crate) struct Auth {
+    users: Vec<UserConfig>,
+}
+
+impl Auth {
+    pub(crate) fn new(users: Vec<UserConfig>) -> Self {
+        Self { users }
+    }
+
+    /// Authentication is only enforced should any users be configured
+    pub(crate) fn is_enabled(&self) -> bool {
+        !self.users.is_empty()
+    }
+
+    /// Checks the credentials of a request, either an API token provided via the `token` query
+    /// parameter, or HTTP basic auth with the API token as the password
+    pub(super) fn authenticate(
+        &self,
+        headers: &HeaderMap,
+        query: Option<&str>,
+    ) -> Option<&UserConfig> {
+        if let Some(token) = query.and_then(query_token) {
+            let hash = hash_token(token.as_str());
+
+            return self
+                .users
+                .iter()
+                .find(|user| hash_eq(&user.token_sha256, &hash));
+        }
+
+        let (name, token) = basic_credentials(headers)?;
+        let hash = hash_token(token.as_str());
+
+        self.users
+            .iter()
+            .find(|user| user.name == name && hash_eq(&user.token_sha256, &hash))
+    }
+}
+
+/// API token provided as a query parameter, for clients which are unable to set headers
+#[derive(Deserialize)]
+struct TokenQuery {
+    token: Option<String>,
+}
+
+fn query_token(query: &str) -> Option<String> {
+    serde_urlencoded::from_str::<TokenQuery>(query)
+        .ok()
+        .and_then(|query| query.token)
+}
+
+/// Decodes the username and password of an `Authorization: Basic` header
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+
+    let (scheme, credentials) = value.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+
+    let credentials = STANDARD.decode(credentials.trim()).ok()?;
+    let credentials = String::from_utf8(credentials).ok()?;
+
+    let (name, password) = credentials.split_once(':')?;
+
+    Some((name.to_string(), password.to_string()))
+}
+
+fn hash_token(token: &str) -> TokenHash {
+    TokenHash::from(<[u8; 32]>::from(Sha256::digest(token.as_bytes())))
+}
+
+#[inline]
+fn hash_eq(a: &TokenHash, b: &TokenHash) -> bool {
+    a.as_bytes().ct_eq(b.as_bytes()).into()
+}
+
+fn unauthorized() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        [(WWW_AUTHENTICATE, HeaderValue::from_static(REALM))],
+    )
+        .into_response()
+}
+
+/// Middleware rejecting requests without valid credentials with `401 Unauthorized`
+pub(super) async fn require_auth(
+    Extension(auth): Extension<Arc<Auth>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !auth.is_enabled() {
+        return next.run(request).await;
+    }
+
+    if auth
+        .authenticate(request.headers(), request.uri().query())
+        .is_none()
+    {
+        return unauthorized();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::rate_limit::RateLimiter;
+    use crate::api::routes::routes;
+    use crate::config::RateLimitConfig;
+    use axum::body::Body;
+    use axum::http::Uri;
+    use tower::ServiceExt;
+    use tower_http::add_extension::AddExtensionLayer;
+
+    const NAME: &str = "calum";
+    const TOKEN: &str = "test";
+
+    fn init_auth() -> Auth {
+        Auth::new(vec![UserConfig {
+            name: NAME.to_string(),
+            token_sha256: hash_token(TOKEN),
+        }])
+    }
+
+    fn basic_headers(name: &str, password: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let credentials = STANDARD.encode(format!("{name}:{password}"));
+
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(format!("Basic {credentials}").as_str()).unwrap(),
+        );
+
+        headers
+    }
+
+    #[test]
+    fn test_hash_token() {
+        assert_eq!(
+            hex::encode(hash_token(TOKEN).as_bytes()),
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        );
+    }
+
+    #[test]
+    fn test_query_token() {
+        let auth = init_auth();
+
+        let user = auth.authenticate(&HeaderMap::new(), Some("size=5&token=test"));
+        assert_eq!(user.map(|user| user.name.as_str()), Some(NAME));
+
+        let user = auth.authenticate(&HeaderMap::new(), Some("token=invalid"));
+        assert!(user.is_none());
+
+        let user = auth.authenticate(&HeaderMap::new(), Some("size=5"));
+        assert!(user.is_none());
+    }
+
+    #[test]
+    fn test_basic_auth() {
+        let auth = init_auth();
+
+        let user = auth.authenticate(&basic_headers(NAME, TOKEN), None);
+        assert_eq!(user.map(|user| user.name.as_str()), Some(NAME));
+
+        let user = auth.authenticate(&basic_headers(NAME, "invalid"), None);
+        assert!(user.is_none());
+
+        let user = auth.authenticate(&basic_headers("other", TOKEN), None);
+        assert!(user.is_none());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer test"));
+        assert!(auth.authenticate(&headers, None).is_none());
+    }
+
+    async fn status(auth: Auth, uri: &'static str) -> StatusCode {
+        let rate_limiter = RateLimiter::new(RateLimitConfig {
+            enabled: false,
+            ..RateLimitConfig::default()
+        });
+
+        let router = routes()
+            .layer(AddExtensionLayer::new(Arc::new(auth)))
+            .layer(AddExtensionLayer::new(Arc::new(rate_limiter)));
+
+        let request = Request::builder()
+            .uri(Uri::from_static(uri))
+            .body(Body::empty())
+            .unwrap();
+
+        router.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized() {
+        for uri in [
+            "/person/19498/combined_credits",
+            "/people/combined_credits?ids=19498",
+            "/lists/favourites",
+            "/person/19498/combined_credits?token=invalid",
+        ] {
+            assert_eq!(status(init_auth(), uri).await, StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ok_exempt() {
+        assert_eq!(status(init_auth(), "/ok").await, StatusCode::OK);
+    }
+
+    #[test]
+    fn test_unauthorized_header() {
+        let response = unauthorized();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response.headers().get(WWW_AUTHENTICATE),
+            Some(&HeaderValue::from_static(REALM))
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use axum::http::header::{ACCEPT, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, VARY};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Number of tracked representations above which the oldest are discarded
+const MAX_TRACKED_REPRESENTATIONS: usize = 10_000;
+
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Records when each representation of a feed, identified by its ETag, was first served so that
+/// an unchanged feed retains its `Last-Modified` date
+#[derive(Debug, Default)]
+pub(crate) struct Validators {
+    first_seen: Mutex<HashMap<u64, DateTime<Utc>>>,
+}
+
+impl Validators {
+    /// The time at which the representation was first served, or `now` if it has not been
+    pub(crate) fn last_modified(&self, etag: u64, now: DateTime<Utc>) -> DateTime<Utc> {
+        let mut first_seen = self
+            .first_seen
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        if first_seen.len() >= MAX_TRACKED_REPRESENTATIONS
+            && !first_seen.contains_key(&etag)
+            && let Some(oldest) = first_seen
+                .iter()
+                .min_by_key(|(_, date)| **date)
+                .map(|(etag, _)| *etag)
+        {
+            first_seen.remove(&oldest);
+        }
+
+        // HTTP dates have a precision of one second
+        let now = DateTime::from_timestamp(now.timestamp(), 0).unwrap_or(now);
+
+        *first_seen.entry(etag).or_insert(now)
+    }
+}
+
+/// Strong validators of a feed representation
+pub(crate) struct Conditional {
+    etag: String,
+    last_modified: DateTime<Utc>,
+}
+
+impl Conditional {
+    pub(crate) fn new(etag: u64, last_modified: DateTime<Utc>) -> Self {
+        Self {
+            etag: format!("\"{etag:016x}\""),
+            last_modified,
+        }
+    }
+
+    /// Evaluates `If-None-Match` and `If-Modified-Since` as per
+    /// [RFC 9110 13.2.2](https://www.rfc-editor.org/rfc/rfc9110#section-13.2.2), the latter is
+    /// ignored if the former is present
+    pub(crate) fn is_not_modified(&self, headers: &HeaderMap) -> bool {
+        if headers.contains_key(IF_NONE_MATCH) {
+            return headers
+                .get_all(IF_NONE_MATCH)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(str::trim)
+                .any(|etag| etag == "*" || etag.trim_start_matches("W/") == self.etag);
+        }
+
+        headers
+            .get(IF_MODIFIED_SINCE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            .is_some_and(|since| self.last_modified <= since)
+    }
+
+    /// The validators alongside `Vary: Accept`, as the format of a feed may be negotiated by the
+    /// `Accept` header, such that caches do not serve one format to a client requesting another
+    fn headers(&self) -> [(axum::http::HeaderName, HeaderValue); 3] {
+        let etag = HeaderValue::from_str(self.etag.as_str()).expect("etag is always valid");
+        let last_modified = HeaderValue::from_str(
+            self.last_modified
+                .format(HTTP_DATE_FORMAT)
+                .to_string()
+                .as_str(),
+        )
+        .expect("http date is always valid");
+
+        [
+            (ETAG, etag),
+            (LAST_MODIFIED, last_modified),
+            (VARY, HeaderValue::from_name(ACCEPT)),
+        ]
+    }
+
+    /// Responds with `304 Not Modified` should the client already hold this representation,
+    /// otherwise with the response produced by `response`
+    pub(crate) fn respond<F>(self, headers: &HeaderMap, response: F) -> Response
+    where
+        F: FnOnce(DateTime<Utc>) -> Response,
+    {
+        if self.is_not_modified(headers) {
+            return (StatusCode::NOT_MODIFIED, self.headers()).into_response();
+        }
+
+        let mut response = response(self.last_modified);
+
+        if response.status().is_success() {
+            response.headers_mut().extend(self.headers());
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeDelta, Timelike};
+
+    const ETAG_VALUE: u64 = 0xabc;
+    const ETAG_HEADER: &str = "\"0000000000000abc\"";
+
+    fn init_conditional() -> Conditional {
+        Conditional::new(
+            ETAG_VALUE,
+            DateTime::from_timestamp(1_747_851_644, 0).unwrap(),
+        )
+    }
+
+    fn headers(name: axum::http::HeaderName, value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn test_if_none_match() {
+        let conditional = init_conditional();
+
+        assert!(conditional.is_not_modified(&headers(IF_NONE_MATCH, ETAG_HEADER)));
+        assert!(
+            conditional.is_not_modified(&headers(IF_NONE_MATCH, "\"a\", \"0000000000000abc\""))
+        );
+        assert!(conditional.is_not_modified(&headers(IF_NONE_MATCH, "W/\"0000000000000abc\"")));
+        assert!(conditional.is_not_modified(&headers(IF_NONE_MATCH, "*")));
+        assert!(!conditional.is_not_modified(&headers(IF_NONE_MATCH, "\"a\"")));
+        assert!(!conditional.is_not_modified(&HeaderMap::new()));
+    }
+
+    #[test]
+    fn test_if_modified_since() {
+        let conditional = init_conditional();
+
+        assert!(
+            conditional
+                .is_not_modified(&headers(IF_MODIFIED_SINCE, "Wed, 21 May 2025 18:20:44 GMT"))
+        );
+        assert!(
+            conditional
+                .is_not_modified(&headers(IF_MODIFIED_SINCE, "Thu, 22 May 2025 18:20:44 GMT"))
+        );
+        assert!(
+            !conditional
+                .is_not_modified(&headers(IF_MODIFIED_SINCE, "Wed, 21 May 2025 18:20:43 GMT"))
+        );
+        assert!(!conditional.is_not_modified(&headers(IF_MODIFIED_SINCE, "invalid")));
+    }
+
+    #[test]
+    fn test_if_none_match_precedence() {
+        let conditional = init_conditional();
+
+        let mut headers = headers(IF_NONE_MATCH, "\"a\"");
+        headers.insert(
+            IF_MODIFIED_SINCE,
+            HeaderValue::from_static("Thu, 22 May 2025 18:20:44 GMT"),
+        );
+
+        assert!(!conditional.is_not_modified(&headers));
+    }
+
+    #[test]
+    fn test_respond() {
+        let response = init_conditional().respond(&HeaderMap::new(), |_| "body".into_response());
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(ETAG).unwrap(), ETAG_HEADER);
+        assert_eq!(
+            response.headers().get(LAST_MODIFIED).unwrap(),
+            "Wed, 21 May 2025 18:20:44 GMT"
+        );
+        assert_eq!(response.headers().get(VARY).unwrap(), "accept");
+
+        let response = init_conditional().respond(&headers(IF_NONE_MATCH, ETAG_HEADER), |_| {
+            "body".into_response()
+        });
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers().get(ETAG).unwrap(), ETAG_HEADER);
+        assert_eq!(response.headers().get(VARY).unwrap(), "accept");
+    }
+
+    #[test]
+    fn test_last_modified_retained() {
+        let validators = Validators::default();
+        let now = DateTime::from_timestamp(1_747_851_644, 500).unwrap();
+        let later = now + TimeDelta::hours(1);
+
+        let expected = DateTime::from_timestamp(1_747_851_644, 0).unwrap();
+
+        assert_eq!(validators.last_modified(ETAG_VALUE, now), expected);
+        assert_eq!(validators.last_modified(ETAG_VALUE, later), expected);
+        assert_eq!(
+            validators.last_modified(ETAG_VALUE + 1, later),
+            later.with_nanosecond(0).unwrap()
+        );
+    }
+}
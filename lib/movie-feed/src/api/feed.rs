@@ -0,0 +1,1811 @@
+pub(crate) mod feed_format;
+pub(crate) mod messages;
+pub(crate) mod person_ids;
+pub(crate) mod query_args;
+pub(crate) mod release_status;
+pub(crate) mod size;
+pub(crate) mod sort_order;
+
+use crate::api::ApiState;
+use crate::api::atom::Atom;
+use crate::api::conditional::{Conditional, Validators};
+use crate::api::feed::feed_format::FeedFormat;
+use crate::api::feed::messages::Messages;
+use crate::api::feed::person_ids::PersonIds;
+use crate::api::feed::query_args::QueryArgs;
+use crate::api::feed::sort_order::SortBy;
+use crate::api::ical::{ICalendar, ICalendarEvent};
+use crate::api::json_feed::{JsonFeed, JsonFeedAuthor, JsonFeedItem, JsonFeedItemExtension};
+use crate::api::process_result::{ProcessedResponse, process_response};
+use crate::api::rss::Rss;
+use crate::store::{CreditChange, CreditSnapshot, Store, StoreError};
+use ammonia::Builder;
+use atom_syndication::{
+    CategoryBuilder, ContentBuilder, EntryBuilder, FeedBuilder, GeneratorBuilder, Link,
+    LinkBuilder, PersonBuilder, Text,
+};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use chrono::{NaiveDate, TimeDelta};
+#[cfg(test)]
+use chrono::{NaiveDateTime, NaiveTime};
+use futures::future::join_all;
+use futures::{StreamExt, stream};
+use itertools::Itertools;
+use rss::extension::atom::AtomExtension;
+use rss::extension::dublincore::{DublinCoreExtension, DublinCoreExtensionBuilder};
+use rss::extension::{Extension, ExtensionBuilder, ExtensionMap};
+use rss::{
+    Category, ChannelBuilder, EnclosureBuilder, Guid, GuidBuilder, ImageBuilder, ItemBuilder,
+};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tmdb::endpoints::v3::configuration::get as get_configuration;
+use tmdb::endpoints::v3::movie::release_dates::get as get_movie_release_dates;
+use tmdb::endpoints::v3::person::combined_credits::get as get_combined_credits;
+use tmdb::endpoints::v3::person::external_ids::get as get_person_external_ids;
+use tmdb::endpoints::v3::person::get as get_person_details;
+use tmdb::image_url::{ImageKind, ImageUrlBuilder};
+use tmdb::localisation::Localisation;
+use tmdb::models::v3::credit::{Credit, CreditType, IsCredit};
+use tmdb::models::v3::external_ids::ExternalIds;
+use tmdb::models::v3::language::Language;
+use tmdb::models::v3::media_type::MediaType;
+use tmdb::models::v3::person_details::PersonDetails;
+use tmdb::{SITE_URL, Tmdb};
+use tokio::task::spawn_blocking;
+use tracing::warn;
+use url::Url;
+
+const TTL: Duration = Duration::from_secs(60 * 60); // 60 minutes
+
+/// The number of names included within the title of a feed spanning multiple people
+const MAX_TITLE_NAMES: usize = 3;
+
+/// The size of the poster attached to each item
+const POSTER_SIZE: &str = "w342";
+/// The size of the profile image of the person whose credits are within the feed
+const PROFILE_SIZE: &str = "w185";
+
+/// The [Media RSS](https://www.rssboard.org/media-rss) namespace
+const MEDIA_NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+/// The number of movies whose release dates are fetched concurrently
+const MAX_CONCURRENT_RELEASE_DATES: usize = 8;
+/// How far ahead of its primary release a movie may be released within a region
+const REGIONAL_RELEASE_LEEWAY: TimeDelta = TimeDelta::weeks(26);
+
+#[cfg(test)]
+const TEST_BUILD_DATE: NaiveDate = NaiveDate::from_ymd_opt(2025, 5, 21).unwrap();
+#[cfg(test)]
+const TEST_BUILD_TIME: NaiveTime = NaiveTime::from_hms_opt(18, 20, 44).unwrap();
+
+/// A person whose credits are included within a feed
+#[derive(Debug, Clone)]
+pub(crate) struct FeedPerson {
+    name: String,
+    tmdb_url: Url,
+}
+
+impl From<&PersonDetails> for FeedPerson {
+    fn from(details: &PersonDetails) -> Self {
+        Self {
+            name: details.name.clone(),
+            tmdb_url: details.tmdb_url(),
+        }
+    }
+}
+
+/// A link to a page related to the feed as a whole, such as the profiles of a person
+#[derive(Debug)]
+pub(crate) struct FeedLink {
+    title: String,
+    url: Url,
+}
+
+/// Channel level data of a feed
+#[derive(Debug)]
+pub(crate) struct FeedMetadata {
+    id: String,
+    title: String,
+    link: Url,
+    description: Option<String>,
+    authors: Vec<FeedPerson>,
+    links: Vec<FeedLink>,
+    /// The path of the profile image of the person, only populated for feeds of a single person
+    image_path: Option<String>,
+    /// The language of the text which is not provided by TMDB
+    messages: Messages,
+}
+
+impl FeedMetadata {
+    /// Metadata of a feed containing the credits of a single person, linking to the profiles of
+    /// the person on other websites if their external ids are known
+    pub(crate) fn person(
+        details: PersonDetails,
+        external_ids: Option<ExternalIds>,
+        messages: Messages,
+    ) -> Self {
+        let person = FeedPerson::from(&details);
+
+        let links = external_ids
+            .map(|external_ids| external_ids.links())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(site, url)| FeedLink {
+                title: site.to_string(),
+                url,
+            })
+            .collect_vec();
+
+        Self {
+            id: person.tmdb_url.to_string(),
+            title: messages.format("feed-title", &[("names", person.name.as_str().into())]),
+            link: person.tmdb_url.clone(),
+            description: details.biography,
+            authors: vec![person],
+            links,
+            image_path: details.profile_path,
+            messages,
+        }
+    }
+
+    /// Metadata of a feed containing the merged credits of multiple people
+    pub(crate) fn people(
+        id: String,
+        title: Option<&str>,
+        people: Vec<FeedPerson>,
+        messages: Messages,
+    ) -> Self {
+        let names = people
+            .iter()
+            .map(|person| person.name.as_str())
+            .collect_vec();
+
+        let title = match title {
+            Some(title) => messages.format("feed-title", &[("names", title.into())]),
+            None if people.len() > MAX_TITLE_NAMES => messages.format(
+                "feed-title-others",
+                &[
+                    ("names", names[..MAX_TITLE_NAMES].join(", ").into()),
+                    ("others", (people.len() - MAX_TITLE_NAMES).into()),
+                ],
+            ),
+            None => messages.format("feed-title", &[("names", names.join(", ").into())]),
+        };
+
+        Self {
+            id,
+            title,
+            link: SITE_URL.clone(),
+            description: Some(
+                messages.format("feed-description", &[("names", names.join(", ").into())]),
+            ),
+            authors: people,
+            links: Vec::new(),
+            image_path: None,
+            messages,
+        }
+    }
+}
+
+/// The role a person has within a credit
+#[derive(Debug, Clone)]
+enum CreditRole {
+    Character(Option<String>),
+    Job { department: String, job: String },
+}
+
+impl From<&Credit> for CreditRole {
+    fn from(credit: &Credit) -> Self {
+        match credit {
+            Credit::Cast(cast) => Self::Character(cast.character().cloned()),
+            Credit::Crew(crew) => Self::Job {
+                department: crew.department().to_string(),
+                job: crew.job().to_string(),
+            },
+        }
+    }
+}
+
+impl CreditRole {
+    /// The character or job, used when listing the roles of multiple people, absent should the
+    /// character not yet be known
+    fn short(&self) -> Option<&str> {
+        match self {
+            CreditRole::Character(character) => character.as_deref(),
+            CreditRole::Job { job, .. } => Some(job.as_str()),
+        }
+    }
+
+    /// The role of the person within a `media:credit` element, which by default is a role name
+    /// of the [European Broadcasting Union](https://www.rssboard.org/media-rss#optional-elements)
+    fn media_role(&self) -> String {
+        match self {
+            CreditRole::Character(_) => "actor".to_string(),
+            CreditRole::Job { job, .. } => job.to_lowercase(),
+        }
+    }
+}
+
+/// A credit to be rendered as a single item of a feed
+#[derive(Debug)]
+pub(crate) struct FeedItem {
+    credit: Credit,
+    /// The release date used for selecting and displaying the credit, the primary release date
+    /// of the credit unless replaced by [`regional_release_dates`]
+    release_date: Option<NaiveDate>,
+    /// The people credited alongside their role, only populated for feeds spanning multiple people
+    people: Vec<(FeedPerson, CreditRole)>,
+    /// When the credit was first seen, see [`record_history`]
+    first_seen: Option<DateTime<Utc>>,
+    /// The most recent change to the title or release date of the credit, see [`record_history`]
+    change: Option<CreditChange>,
+}
+
+impl From<Credit> for FeedItem {
+    fn from(credit: Credit) -> Self {
+        Self {
+            release_date: credit.release_date().copied(),
+            credit,
+            people: Vec::new(),
+            first_seen: None,
+            change: None,
+        }
+    }
+}
+
+/// Fetches the details and combined credits of a person, any error is converted into the
+/// [`Response`] which should be returned to the client
+pub(crate) async fn fetch_person_credits(
+    tmdb: &Tmdb,
+    person_id: i32,
+    localisation: &Localisation,
+) -> Result<(PersonDetails, Vec<Credit>), Response> {
+    let person_id_str = person_id.to_string();
+
+    let (details, credits) = tokio::join!(
+        get_person_details(tmdb, person_id, localisation),
+        get_combined_credits(tmdb, person_id_str.as_str(), localisation),
+    );
+
+    let details = match process_response(details) {
+        ProcessedResponse::Ok(details) => details,
+        ProcessedResponse::Err(error) => {
+            warn!("{error}");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+        }
+        ProcessedResponse::Response(response) => return Err(response),
+    };
+
+    let credits = match process_response(credits) {
+        ProcessedResponse::Ok(credits) => credits,
+        ProcessedResponse::Err(error) => {
+            warn!("{error}");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+        }
+        ProcessedResponse::Response(response) => return Err(response),
+    };
+
+    let cast_iter = credits.cast.into_iter().map(Credit::Cast);
+    let crew_iter = credits.crew.into_iter().map(Credit::Crew);
+
+    let credits = cast_iter.merge_by(crew_iter, |_a, _b| true).collect_vec();
+
+    Ok((details, credits))
+}
+
+/// Builds the combined credits feed of a single person, as served by both the TMDB and IMDb id
+/// routes
+pub(crate) async fn person_feed(
+    api_state: &ApiState,
+    person_id: i32,
+    query: &QueryArgs,
+    headers: &HeaderMap,
+) -> Response {
+    let localisation = query.localisation();
+
+    let (person_credits, external_ids, images) = tokio::join!(
+        fetch_person_credits(&api_state.tmdb, person_id, &localisation),
+        get_person_external_ids(&api_state.tmdb, person_id),
+        image_url_builder(&api_state.tmdb),
+    );
+
+    let (details, credits) = match person_credits {
+        Ok(person_credits) => person_credits,
+        Err(response) => return response,
+    };
+
+    let external_ids = match external_ids {
+        Ok(external_ids) => Some(external_ids),
+        Err(error) => {
+            warn!("unable to fetch the external ids of person {person_id}: {error}");
+            None
+        }
+    };
+
+    let mut items = credits.into_iter().map(FeedItem::from).collect_vec();
+    record_history(&api_state.store, &mut items, query.lang.as_ref()).await;
+    regional_release_dates(&api_state.tmdb, &mut items, query).await;
+
+    let format = query.feed_format(headers);
+    let items = select_items(items, query, format);
+
+    render(
+        format,
+        FeedMetadata::person(details, external_ids, Messages::new(query.lang.as_ref())),
+        items,
+        &images,
+        headers,
+        &api_state.validators,
+    )
+}
+
+/// Fetches the configuration of TMDB images, falling back to the default base url and sizes
+/// should it be unavailable
+pub(crate) async fn image_url_builder(tmdb: &Tmdb) -> ImageUrlBuilder {
+    match get_configuration(tmdb).await {
+        Ok(configuration) => ImageUrlBuilder::from(configuration.images),
+        Err(error) => {
+            warn!("unable to fetch the tmdb configuration: {error}");
+            ImageUrlBuilder::default()
+        }
+    }
+}
+
+/// Concurrently fetches the details and combined credits of each person, see
+/// [`fetch_person_credits`]
+pub(crate) async fn fetch_people_credits(
+    tmdb: &Tmdb,
+    person_ids: &PersonIds,
+    localisation: &Localisation,
+) -> Result<Vec<(PersonDetails, Vec<Credit>)>, Response> {
+    join_all(
+        person_ids
+            .iter()
+            .map(|person_id| fetch_person_credits(tmdb, *person_id, localisation)),
+    )
+    .await
+    .into_iter()
+    .collect()
+}
+
+/// Merges the credits of multiple people, de-duplicating credits which share a [`credit_guid`]
+/// and annotating each with the people it belongs to
+pub(crate) fn merge_credits(
+    people_credits: Vec<(PersonDetails, Vec<Credit>)>,
+) -> (Vec<FeedPerson>, Vec<FeedItem>) {
+    let mut people = Vec::with_capacity(people_credits.len());
+    let mut items: Vec<FeedItem> = Vec::new();
+    let mut indexes: HashMap<String, usize> = HashMap::new();
+
+    for (details, credits) in people_credits {
+        let person = FeedPerson::from(&details);
+
+        for credit in credits {
+            let role = CreditRole::from(&credit);
+
+            match indexes.get(&credit_guid(&credit).value) {
+                Some(index) => items[*index].people.push((person.clone(), role)),
+                None => {
+                    indexes.insert(credit_guid(&credit).value, items.len());
+                    items.push(FeedItem {
+                        release_date: credit.release_date().copied(),
+                        credit,
+                        people: vec![(person.clone(), role)],
+                        first_seen: None,
+                        change: None,
+                    });
+                }
+            }
+        }
+
+        people.push(person);
+    }
+
+    (people, items)
+}
+
+/// Annotates each item with the time at which its [`credit_uid`] was first seen and the most
+/// recent change to its title or release date, recording any which have not been seen before.
+/// Should the store be unavailable, the items are left without either.
+///
+/// Credits are identified by [`credit_uid`], which excludes the title and release date such that
+/// a credit is not seen anew should either change, and includes the TMDB credit id and as such is
+/// specific to the person credited. Localised feeds track changes separately for each `language`
+/// so that the titles of other languages are not reported as changes, whereas the time at which a
+/// credit was first seen is shared by every language.
+pub(crate) async fn record_history(
+    store: &Arc<Store>,
+    items: &mut [FeedItem],
+    language: Option<&Language>,
+) {
+    let snapshot_uid = |credit: &Credit| match language {
+        None => credit_uid(credit),
+        Some(language) => format!("{}:{language}", credit_uid(credit)),
+    };
+
+    let store = store.clone();
+
+    let uids = items
+        .iter()
+        .map(|item| credit_uid(&item.credit))
+        .collect_vec();
+
+    let snapshots = items
+        .iter()
+        .map(|item| {
+            let snapshot = CreditSnapshot {
+                title: item.credit.title().to_string(),
+                release_date: item.credit.release_date().copied(),
+            };
+
+            (snapshot_uid(&item.credit), snapshot)
+        })
+        .collect_vec();
+
+    let history = spawn_blocking(move || {
+        let now = build_date();
+
+        Ok::<_, StoreError>((
+            store.first_seen(&uids, now)?,
+            store.record_snapshots(&snapshots, now)?,
+        ))
+    })
+    .await;
+
+    let (first_seen, changes) = match history {
+        Ok(Ok(history)) => history,
+        Ok(Err(error)) => {
+            warn!("unable to record the history of credits: {error}");
+            return;
+        }
+        Err(error) => {
+            warn!("unable to record the history of credits: {error}");
+            return;
+        }
+    };
+
+    for item in items {
+        item.first_seen = first_seen.get(&credit_uid(&item.credit)).copied();
+        item.change = changes.get(&snapshot_uid(&item.credit)).cloned();
+    }
+}
+
+/// Replaces the release date of each movie with its release date within the region requested by
+/// the query or, should it not request one, the default region of `tmdb`, if any. Movies without a release of the requested type within the region are left
+/// without a release date, whereas those whose release dates could not be fetched keep their
+/// primary release date.
+///
+/// Regional releases rarely precede the primary release, so movies whose primary release date is
+/// well beyond the latest date accepted by [`QueryArgs::release_status`] are skipped, being
+/// excluded by [`select_items`] regardless. At most [`MAX_CONCURRENT_RELEASE_DATES`] requests are
+/// made at once.
+pub(crate) async fn regional_release_dates(tmdb: &Tmdb, items: &mut [FeedItem], query: &QueryArgs) {
+    let Some((region, release_type)) = query.regional_release(tmdb.localisation().region.as_ref())
+    else {
+        return;
+    };
+
+    // statuses accepting undated credits also accept movies without a regional release
+    let latest_release_date = if query.release_status.check(None) {
+        None
+    } else {
+        query
+            .release_status
+            .latest_release_date()
+            .and_then(|date| date.checked_add_signed(REGIONAL_RELEASE_LEEWAY))
+    };
+
+    let movie_ids = items
+        .iter()
+        .filter(|item| item.credit.media_type() == MediaType::Movie)
+        .filter(|item| match (item.release_date, latest_release_date) {
+            (Some(release_date), Some(latest_release_date)) => release_date <= latest_release_date,
+            _ => true,
+        })
+        .filter_map(|item| i32::try_from(item.credit.id()).ok())
+        .unique()
+        .collect_vec();
+
+    let release_dates: HashMap<i32, Option<NaiveDate>> = stream::iter(movie_ids)
+        .map(|movie_id| async move { (movie_id, get_movie_release_dates(tmdb, movie_id).await) })
+        .buffer_unordered(MAX_CONCURRENT_RELEASE_DATES)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .filter_map(|(movie_id, release_dates)| match release_dates {
+            Ok(release_dates) => Some((movie_id, release_dates.release_date(region, release_type))),
+            Err(error) => {
+                warn!("unable to fetch the release dates of movie {movie_id}: {error}");
+                None
+            }
+        })
+        .collect();
+
+    for item in items {
+        if item.credit.media_type() != MediaType::Movie {
+            continue;
+        }
+
+        let Ok(movie_id) = i32::try_from(item.credit.id()) else {
+            continue;
+        };
+
+        if let Some(release_date) = release_dates.get(&movie_id) {
+            item.release_date = *release_date;
+        }
+    }
+}
+
+/// Filters, sorts and truncates the items as specified by the query. Items without a release date
+/// are excluded from [`FeedFormat::Ics`] feeds prior to truncating, as they cannot be rendered as
+/// events.
+pub(crate) fn select_items(
+    items: Vec<FeedItem>,
+    query: &QueryArgs,
+    format: FeedFormat,
+) -> Vec<FeedItem> {
+    let mut items = items
+        .into_iter()
+        .filter(|item| query.release_status.check(item.release_date.as_ref()))
+        .filter(|item| format != FeedFormat::Ics || item.release_date.is_some())
+        .collect_vec();
+
+    items.sort_by(|a, b| match query.sort_by {
+        SortBy::ReleaseDate => query
+            .sort_order
+            .sort_dates(a.release_date.as_ref(), b.release_date.as_ref()),
+        SortBy::FirstSeen => query
+            .sort_order
+            .sort_dates(a.first_seen.as_ref(), b.first_seen.as_ref()),
+    });
+
+    items.truncate(query.size.get());
+
+    items
+}
+
+/// Renders the feed in the requested format, responding with `304 Not Modified` should the
+/// client's cached representation match
+pub(crate) fn render(
+    format: FeedFormat,
+    metadata: FeedMetadata,
+    items: Vec<FeedItem>,
+    images: &ImageUrlBuilder,
+    headers: &HeaderMap,
+    validators: &Validators,
+) -> Response {
+    let etag = feed_etag(format, &metadata, &items);
+    let conditional = Conditional::new(etag, validators.last_modified(etag, build_date()));
+
+    conditional.respond(headers, |updated| match format {
+        FeedFormat::Rss => rss_feed(metadata, items, images, updated).into_response(),
+        FeedFormat::Atom => atom_feed(metadata, items, images, updated).into_response(),
+        FeedFormat::Json => json_feed(metadata, items, images).into_response(),
+        FeedFormat::Ics => ical_feed(metadata, items, updated).into_response(),
+    })
+}
+
+/// A [`stable_hash`] of the format, channel level data and the [`credit_guid`] and content of
+/// each item, identifying a representation of a feed regardless of when it was built or which
+/// release of Rust it was built by
+fn feed_etag(format: FeedFormat, metadata: &FeedMetadata, items: &[FeedItem]) -> u64 {
+    let mut fields = vec![
+        format!("{format:?}"),
+        metadata.id.clone(),
+        metadata.title.clone(),
+        metadata.link.to_string(),
+        metadata.description.clone().unwrap_or_default(),
+        metadata.image_path.clone().unwrap_or_default(),
+        metadata.authors.len().to_string(),
+    ];
+
+    for person in &metadata.authors {
+        fields.push(person.name.clone());
+    }
+
+    fields.push(metadata.links.len().to_string());
+
+    for link in &metadata.links {
+        fields.push(link.title.clone());
+        fields.push(link.url.to_string());
+    }
+
+    for item in items {
+        let categories = item_categories(item, metadata.messages);
+
+        fields.extend([
+            credit_guid(&item.credit).value,
+            item.credit.credit_id().to_string(),
+            item.credit.overview().cloned().unwrap_or_default(),
+            item_description(item, metadata.messages),
+            item.first_seen
+                .map(|first_seen| first_seen.timestamp().to_string())
+                .unwrap_or_default(),
+            item.credit.poster_path().unwrap_or_default().to_string(),
+            categories.len().to_string(),
+        ]);
+        fields.extend(categories);
+    }
+
+    stable_hash(fields)
+}
+
+/// A [`stable_hash`] of the following fields of a Credit
+/// - ID
+/// - Title
+/// - Release Date
+/// - Media Type
+/// - Credit Type
+fn credit_guid(credit: &impl IsCredit) -> Guid {
+    let hash = stable_hash([
+        credit.id().to_string().as_str(),
+        credit.title(),
+        credit
+            .release_date()
+            .map(NaiveDate::to_string)
+            .unwrap_or_default()
+            .as_str(),
+        credit.media_type().to_string().as_str(),
+        credit_type_name(credit.credit_type()),
+    ]);
+
+    GuidBuilder::default()
+        .value(hash.to_string())
+        .permalink(false)
+        .build()
+}
+
+/// A [`stable_hash`] of the following fields of a Credit, used to identify calendar events
+/// - ID
+/// - Credit ID
+/// - Media Type
+/// - Credit Type
+///
+/// Unlike [`credit_guid`], the title and release date are excluded so that calendar clients
+/// update the existing event should either change.
+fn credit_uid(credit: &impl IsCredit) -> String {
+    let hash = stable_hash([
+        credit.id().to_string().as_str(),
+        credit.credit_id(),
+        credit.media_type().to_string().as_str(),
+        credit_type_name(credit.credit_type()),
+    ]);
+
+    format!("{hash}@movie-feed")
+}
+
+/// A hash of `fields` which, unlike [`DefaultHasher`](std::hash::DefaultHasher), is stable across releases of Rust such
+/// that it may identify a credit within the [`Store`] and the caches of clients
+fn stable_hash<I: IntoIterator<Item = S>, S: AsRef<str>>(fields: I) -> u64 {
+    let mut hasher = Sha256::new();
+
+    for field in fields {
+        hasher.update(field.as_ref().as_bytes());
+        hasher.update([0x1f]); // unit separator, such that ["ab", "c"] and ["a", "bc"] differ
+    }
+
+    let digest = hasher.finalize();
+    u64::from_be_bytes(
+        digest[..8]
+            .try_into()
+            .expect("sha-256 digests are 32 bytes"),
+    )
+}
+
+fn credit_type_name(credit_type: CreditType) -> &'static str {
+    match credit_type {
+        CreditType::Cast => "cast",
+        CreditType::Crew => "crew",
+    }
+}
+
+#[inline]
+fn sanitise_text<S: AsRef<str>>(text: S) -> String {
+    let mut s = text.as_ref().replace('\n', "<br>");
+
+    let mut tags = HashSet::new();
+    tags.insert("br");
+    tags.insert("p");
+
+    s = Builder::new().tags(tags).clean(s.as_ref()).to_string();
+
+    s
+}
+
+/// Categories of an item, in order: media type, character (if any), genres and the names of the
+/// people credited (if any)
+fn item_categories(item: &FeedItem, messages: Messages) -> Vec<String> {
+    let credit = &item.credit;
+    let mut categories = vec![sanitise_text(messages.media_type(&credit.media_type()))];
+
+    if item.people.is_empty()
+        && let Credit::Cast(cast) = credit
+        && let Some(character) = cast.character()
+    {
+        categories.push(sanitise_text(character));
+    }
+
+    categories.extend(credit.genres().iter().map(|genre| messages.genre(*genre)));
+
+    categories.extend(
+        item.people
+            .iter()
+            .map(|(person, _)| sanitise_text(person.name.as_str()))
+            .unique(),
+    );
+
+    categories
+}
+
+/// The role(s) of an item in the form of `Character: X` or `People: A (X), B (Y)` for items
+/// spanning multiple people
+fn item_roles(item: &FeedItem, line_break: &str, messages: Messages) -> String {
+    let tba = messages.get("tba");
+
+    if !item.people.is_empty() {
+        let people = item
+            .people
+            .iter()
+            .chunk_by(|(person, _)| person.name.as_str())
+            .into_iter()
+            .map(|(name, roles)| {
+                format!(
+                    "{name} ({})",
+                    roles
+                        .map(|(_, role)| role.short().unwrap_or(tba.as_str()))
+                        .join(", ")
+                )
+            })
+            .join(", ");
+
+        return format!("{}: {people}", messages.get("people"));
+    }
+
+    match CreditRole::from(&item.credit) {
+        CreditRole::Character(character) => {
+            format!(
+                "{}: {}",
+                messages.get("character"),
+                character.as_deref().unwrap_or(tba.as_str())
+            )
+        }
+        CreditRole::Job { department, job } => {
+            format!(
+                "{}: {department}{line_break}{}: {job}",
+                messages.get("department"),
+                messages.get("job")
+            )
+        }
+    }
+}
+
+/// Describes the most recent change to the title or release date of an item, if any, in the form
+/// of `Release date changed: X → Y`
+fn item_changes(item: &FeedItem, messages: Messages) -> Vec<String> {
+    let Some(change) = &item.change else {
+        return Vec::new();
+    };
+
+    let credit = &item.credit;
+    let mut changes = Vec::new();
+
+    if change.previous.release_date.as_ref() != credit.release_date() {
+        changes.push(messages.format(
+            "release-date-changed",
+            &[
+                (
+                    "previous",
+                    messages.date(change.previous.release_date.as_ref()).into(),
+                ),
+                ("current", messages.date(credit.release_date()).into()),
+            ],
+        ));
+    }
+
+    if change.previous.title != credit.title() {
+        changes.push(messages.format(
+            "title-changed",
+            &[
+                ("previous", change.previous.title.as_str().into()),
+                ("current", credit.title().into()),
+            ],
+        ));
+    }
+
+    changes
+}
+
+/// The sanitised html description of an item
+fn item_description(item: &FeedItem, messages: Messages) -> String {
+    let credit = &item.credit;
+
+    let mut description = credit
+        .overview_len()
+        .map(String::with_capacity)
+        .unwrap_or_default();
+
+    let changes = item_changes(item, messages);
+    if !changes.is_empty() {
+        description.push_str("<p>");
+        description.push_str(changes.join("<br>").as_str());
+        description.push_str("</p>");
+    }
+
+    description.push_str("<p>");
+    description.push_str(item_roles(item, "<br>", messages).as_str());
+
+    description.push_str("<br>");
+    description.push_str(messages.get("genres").as_str());
+    description.push_str(": ");
+    credit.genres().iter().enumerate().for_each(|(i, genre)| {
+        if i > 0 {
+            description.push_str(", ");
+        }
+
+        description.push_str(messages.genre(*genre).as_str());
+    });
+
+    description.push_str("<br>");
+    description.push_str(messages.get("language").as_str());
+    description.push_str(": ");
+    description.push_str(credit.original_language());
+
+    description.push_str("<br>");
+    description.push_str(messages.get("release-date").as_str());
+    description.push_str(": ");
+    description.push_str(messages.date(item.release_date.as_ref()).as_str());
+
+    if let Some(overview) = credit.overview() {
+        description.push_str("</p><p>");
+        description.push_str(overview.as_str());
+        description.push_str("</p>");
+    }
+
+    sanitise_text(description)
+}
+
+/// The plain text description of an item
+fn item_plain_description(item: &FeedItem, messages: Messages) -> String {
+    let credit = &item.credit;
+    let mut description = String::new();
+
+    let changes = item_changes(item, messages);
+    if !changes.is_empty() {
+        description.push_str(changes.join("\n").as_str());
+        description.push_str("\n\n");
+    }
+
+    description.push_str(item_roles(item, "\n", messages).as_str());
+
+    description.push('\n');
+    description.push_str(messages.get("genres").as_str());
+    description.push_str(": ");
+    description.push_str(
+        credit
+            .genres()
+            .iter()
+            .map(|genre| messages.genre(*genre))
+            .join(", ")
+            .as_str(),
+    );
+
+    description.push('\n');
+    description.push_str(messages.get("language").as_str());
+    description.push_str(": ");
+    description.push_str(credit.original_language());
+
+    if let Some(overview) = credit.overview() {
+        description.push_str("\n\n");
+        description.push_str(overview.as_str());
+    }
+
+    description
+}
+
+#[inline]
+fn build_date() -> DateTime<Utc> {
+    #[cfg(test)]
+    let build_date = DateTime::from_naive_utc_and_offset(
+        NaiveDateTime::new(TEST_BUILD_DATE, TEST_BUILD_TIME),
+        Utc,
+    );
+    #[cfg(not(test))]
+    let build_date = Utc::now();
+
+    build_date
+}
+
+/// The url of the poster of an item, if any
+fn item_poster(item: &FeedItem, images: &ImageUrlBuilder) -> Option<Url> {
+    images.build(ImageKind::Poster, POSTER_SIZE, item.credit.poster_path()?)
+}
+
+/// The url of the profile image of the person whose credits are within the feed, if any
+fn feed_image(metadata: &FeedMetadata, images: &ImageUrlBuilder) -> Option<Url> {
+    images.build(
+        ImageKind::Profile,
+        PROFILE_SIZE,
+        metadata.image_path.as_deref()?,
+    )
+}
+
+/// The people credited within an item alongside their role, falling back to the people of the
+/// feed for items which do not span multiple people
+fn item_credits<'a>(
+    item: &'a FeedItem,
+    metadata: &'a FeedMetadata,
+) -> Vec<(&'a FeedPerson, CreditRole)> {
+    if !item.people.is_empty() {
+        return item
+            .people
+            .iter()
+            .map(|(person, role)| (person, role.clone()))
+            .collect_vec();
+    }
+
+    let role = CreditRole::from(&item.credit);
+
+    metadata
+        .authors
+        .iter()
+        .map(|person| (person, role.clone()))
+        .collect_vec()
+}
+
+/// An element of the [Media RSS](https://www.rssboard.org/media-rss) namespace
+fn media_element<const N: usize>(
+    name: &str,
+    attrs: [(&str, String); N],
+    value: Option<String>,
+) -> Extension {
+    ExtensionBuilder::default()
+        .name(format!("media:{name}"))
+        .attrs(
+            attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect::<BTreeMap<_, _>>(),
+        )
+        .value(value)
+        .build()
+}
+
+/// The [Media RSS](https://www.rssboard.org/media-rss) elements of an item, consisting of the
+/// poster as `media:content` and `media:thumbnail` alongside a `media:credit` for each role
+fn item_media(
+    item: &FeedItem,
+    metadata: &FeedMetadata,
+    images: &ImageUrlBuilder,
+) -> BTreeMap<String, Vec<Extension>> {
+    let mut media = BTreeMap::new();
+
+    if let Some(poster) = item_poster(item, images) {
+        media.insert(
+            "content".to_string(),
+            vec![media_element(
+                "content",
+                [
+                    ("url", poster.to_string()),
+                    ("type", "image/jpeg".to_string()),
+                    ("medium", "image".to_string()),
+                ],
+                None,
+            )],
+        );
+        media.insert(
+            "thumbnail".to_string(),
+            vec![media_element(
+                "thumbnail",
+                [("url", poster.to_string())],
+                None,
+            )],
+        );
+    }
+
+    let credits = item_credits(item, metadata)
+        .into_iter()
+        .map(|(person, role)| {
+            media_element(
+                "credit",
+                [("role", role.media_role())],
+                Some(sanitise_text(&person.name)),
+            )
+        })
+        .collect_vec();
+
+    if !credits.is_empty() {
+        media.insert("credit".to_string(), credits);
+    }
+
+    media
+}
+
+/// The [Dublin Core](https://www.dublincore.org/specifications/dublin-core/dcmi-terms/) elements
+/// of an item, the credited people as `dc:creator` and the release date as `dc:date`
+fn item_dublin_core(item: &FeedItem, metadata: &FeedMetadata) -> DublinCoreExtension {
+    DublinCoreExtensionBuilder::default()
+        .creators(
+            item_credits(item, metadata)
+                .into_iter()
+                .map(|(person, _)| sanitise_text(&person.name))
+                .unique()
+                .collect_vec(),
+        )
+        .dates(
+            item.release_date
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .into_iter()
+                .collect_vec(),
+        )
+        .build()
+}
+
+fn rss_feed(
+    metadata: FeedMetadata,
+    items: Vec<FeedItem>,
+    images: &ImageUrlBuilder,
+    updated: DateTime<Utc>,
+) -> Rss {
+    let messages = metadata.messages;
+    let items = items
+        .iter()
+        .map(|item| {
+            let credit = &item.credit;
+            let mut rss_item = ItemBuilder::default();
+
+            if let Some(poster) = item_poster(item, images) {
+                rss_item.enclosure(Some(
+                    EnclosureBuilder::default()
+                        .url(poster.to_string())
+                        .length("0")
+                        .mime_type("image/jpeg")
+                        .build(),
+                ));
+            }
+
+            rss_item
+                .extensions(ExtensionMap::from([(
+                    "media".to_string(),
+                    item_media(item, &metadata, images),
+                )]))
+                .dublin_core_ext(Some(item_dublin_core(item, &metadata)))
+                .guid(Some(credit_guid(credit)))
+                .categories(
+                    item_categories(item, messages)
+                        .into_iter()
+                        .map(Category::from)
+                        .collect_vec(),
+                )
+                .link(credit.tmdb_media_url().to_string())
+                .pub_date(item.first_seen.map(|date| date.to_rfc2822()))
+                .title(Some(credit.title().to_string()))
+                .description(item_description(item, messages));
+
+            rss_item.build()
+        })
+        .collect_vec();
+
+    let mut channel = ChannelBuilder::default();
+
+    if let Some(image) = feed_image(&metadata, images) {
+        channel.image(Some(
+            ImageBuilder::default()
+                .url(image.to_string())
+                .title(sanitise_text(metadata.title.clone()))
+                .link(metadata.link.to_string())
+                .build(),
+        ));
+    }
+
+    channel
+        .namespaces(BTreeMap::from([(
+            "media".to_string(),
+            MEDIA_NAMESPACE.to_string(),
+        )]))
+        .title(sanitise_text(metadata.title))
+        .link(metadata.link)
+        .last_build_date(updated.format("%a, %d %b %Y %H:%M %Z").to_string())
+        .generator(Some(
+            "Movie Feed <https://github.com/calum4/movie-feed/>".to_string(),
+        ))
+        .docs(Some(
+            "https://www.rssboard.org/rss-specification".to_string(),
+        ))
+        .ttl(Some((TTL.as_secs() * 60).to_string()))
+        .items(items);
+
+    if let Some(description) = metadata.description {
+        channel.description(sanitise_text(description));
+    }
+
+    channel.dublin_core_ext(Some(
+        DublinCoreExtensionBuilder::default()
+            .creators(
+                metadata
+                    .authors
+                    .iter()
+                    .map(|person| sanitise_text(&person.name))
+                    .collect_vec(),
+            )
+            .build(),
+    ));
+
+    if !metadata.links.is_empty() {
+        channel.atom_ext(Some(AtomExtension {
+            links: metadata.links.iter().map(atom_link).collect_vec(),
+        }));
+    }
+
+    Rss::new(channel.build())
+}
+
+/// A link to a related page, used by both the atom feed and the atom extension of the rss feed
+fn atom_link(link: &FeedLink) -> Link {
+    LinkBuilder::default()
+        .href(link.url.to_string())
+        .rel("related")
+        .title(Some(link.title.clone()))
+        .build()
+}
+
+/// When an item was last modified, being when the most recent change to it was detected or,
+/// should it not have changed, when it was first seen
+fn item_updated(item: &FeedItem) -> Option<DateTime<Utc>> {
+    item.change
+        .as_ref()
+        .map(|change| change.detected)
+        .or(item.first_seen)
+}
+
+fn atom_person(person: &FeedPerson) -> atom_syndication::Person {
+    PersonBuilder::default()
+        .name(person.name.as_str())
+        .uri(Some(person.tmdb_url.to_string()))
+        .build()
+}
+
+fn atom_feed(
+    metadata: FeedMetadata,
+    items: Vec<FeedItem>,
+    images: &ImageUrlBuilder,
+    updated: DateTime<Utc>,
+) -> Atom {
+    let messages = metadata.messages;
+    let updated = updated.fixed_offset();
+
+    let entries = items
+        .iter()
+        .map(|item| {
+            let credit = &item.credit;
+            let mut entry = EntryBuilder::default();
+
+            entry
+                .id(format!(
+                    "urn:movie-feed:credit:{}",
+                    credit_guid(credit).value
+                ))
+                .title(credit.title())
+                .updated(item_updated(item).map_or(updated, |date| date.fixed_offset()))
+                .published(item.first_seen.map(|date| date.fixed_offset()))
+                .authors(
+                    item.people
+                        .iter()
+                        .map(|(person, _)| atom_person(person))
+                        .collect_vec(),
+                )
+                .links(
+                    [LinkBuilder::default()
+                        .href(credit.tmdb_media_url().to_string())
+                        .build()]
+                    .into_iter()
+                    .chain(item_poster(item, images).map(|poster| {
+                        LinkBuilder::default()
+                            .href(poster.to_string())
+                            .rel("enclosure")
+                            .mime_type(Some("image/jpeg".to_string()))
+                            .build()
+                    }))
+                    .collect_vec(),
+                )
+                .categories(
+                    item_categories(item, messages)
+                        .into_iter()
+                        .map(|term| CategoryBuilder::default().term(term).build())
+                        .collect_vec(),
+                )
+                .content(
+                    ContentBuilder::default()
+                        .content_type(Some("html".to_string()))
+                        .value(Some(item_description(item, messages)))
+                        .build(),
+                );
+
+            entry.build()
+        })
+        .collect_vec();
+
+    let mut feed = FeedBuilder::default();
+
+    feed.logo(feed_image(&metadata, images).map(String::from));
+
+    feed.id(metadata.id)
+        .title(sanitise_text(metadata.title))
+        .updated(updated)
+        .authors(metadata.authors.iter().map(atom_person).collect_vec())
+        .links(
+            [LinkBuilder::default().href(metadata.link).build()]
+                .into_iter()
+                .chain(metadata.links.iter().map(atom_link))
+                .collect_vec(),
+        )
+        .generator(Some(
+            GeneratorBuilder::default()
+                .value("Movie Feed")
+                .uri(Some("https://github.com/calum4/movie-feed/".to_string()))
+                .build(),
+        ))
+        .entries(entries);
+
+    if let Some(description) = metadata.description {
+        feed.subtitle(Some(Text::html(sanitise_text(description))));
+    }
+
+    Atom::new(feed.build())
+}
+
+fn json_feed_author(person: &FeedPerson) -> JsonFeedAuthor {
+    JsonFeedAuthor {
+        name: person.name.clone(),
+        url: Some(person.tmdb_url.to_string()),
+    }
+}
+
+fn json_feed(metadata: FeedMetadata, items: Vec<FeedItem>, images: &ImageUrlBuilder) -> JsonFeed {
+    let messages = metadata.messages;
+    let items = items
+        .iter()
+        .map(|item| {
+            let credit = &item.credit;
+
+            JsonFeedItem {
+                id: credit_guid(credit).value,
+                url: credit.tmdb_media_url().to_string(),
+                title: credit.title().to_string(),
+                content_html: item_description(item, messages),
+                summary: credit.overview().cloned(),
+                image: item_poster(item, images).map(String::from),
+                tags: item_categories(item, messages),
+                date_published: item.first_seen,
+                date_modified: item.change.as_ref().map(|change| change.detected),
+                authors: item
+                    .people
+                    .iter()
+                    .map(|(person, _)| json_feed_author(person))
+                    .collect_vec(),
+                extension: JsonFeedItemExtension {
+                    release_date: item.release_date,
+                },
+            }
+        })
+        .collect_vec();
+
+    let icon = feed_image(&metadata, images).map(String::from);
+    let mut feed = JsonFeed::new(sanitise_text(metadata.title));
+
+    feed.home_page_url = Some(metadata.link.to_string());
+    feed.icon = icon;
+    feed.description = metadata.description.map(sanitise_text);
+    feed.authors = metadata.authors.iter().map(json_feed_author).collect_vec();
+    feed.items = items;
+
+    feed
+}
+
+fn ical_feed(metadata: FeedMetadata, items: Vec<FeedItem>, timestamp: DateTime<Utc>) -> ICalendar {
+    let messages = metadata.messages;
+    let events = items
+        .iter()
+        .filter_map(|item| {
+            let credit = &item.credit;
+            let date = item.release_date?;
+
+            Some(ICalendarEvent {
+                uid: credit_uid(credit),
+                timestamp,
+                date,
+                summary: credit.title().to_string(),
+                description: Some(item_plain_description(item, messages)),
+                url: credit.tmdb_media_url().to_string(),
+                categories: item_categories(item, messages),
+            })
+        })
+        .collect_vec();
+
+    ICalendar::new(metadata.title, metadata.description, TTL, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::feed::release_status::ReleaseStatus;
+    use crate::api::feed::size::Size;
+    use crate::api::feed::sort_order::SortReleaseDates;
+    use std::str::FromStr;
+    use tmdb::models::v3::cast::{Cast, MovieCast};
+    use tmdb::models::v3::region::Region;
+    use tmdb::models::v3::release_dates::ReleaseType;
+    use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+    use tmdb_test_utils::api::v3::movie::release_dates::mock_get_movie_release_dates;
+    use tmdb_test_utils::mockito::Matcher;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    fn init_movie_cast() -> MovieCast {
+        MovieCast {
+            id: 870028,
+            title: "The Accountant²".to_string(),
+            original_title: "The Accountant²".to_string(),
+            character: Some("Braxton Wolff".to_string()),
+            genres: Vec::new(),
+            release_date: NaiveDate::from_ymd_opt(2025, 4, 23),
+            overview: None,
+            original_language: "en".to_string(),
+            credit_id: "example-credit-id".to_string(),
+            poster_path: Some("/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg".to_string()),
+            backdrop_path: Some("/rdxdvXQGqCj2Vb7SvTL8gdxlSzI.jpg".to_string()),
+        }
+    }
+
+    fn init_person(id: i32, name: &str) -> PersonDetails {
+        PersonDetails {
+            adult: false,
+            also_known_as: Vec::new(),
+            biography: None,
+            birthday: None,
+            deathday: None,
+            gender: Default::default(),
+            homepage: None,
+            id,
+            imdb_id: None,
+            known_for_department: "Acting".to_string(),
+            name: name.to_string(),
+            place_of_birth: None,
+            popularity: 0.0,
+            profile_path: None,
+        }
+    }
+
+    #[test]
+    fn test_credit_guid_stable() {
+        let credit = Credit::Cast(Cast::Movie(init_movie_cast()));
+
+        assert_eq!(credit_guid(&credit).value, "7745209040517900570");
+        assert_eq!(credit_uid(&credit), "3648479588211812337@movie-feed");
+        assert_ne!(stable_hash(["ab", "c"]), stable_hash(["a", "bc"]));
+    }
+
+    #[test]
+    fn test_credit_uid_ignores_release_date() {
+        let mut cast = init_movie_cast();
+
+        let uid = credit_uid(&Credit::Cast(Cast::Movie(cast.clone())));
+        let guid = credit_guid(&Credit::Cast(Cast::Movie(cast.clone())));
+
+        cast.release_date = NaiveDate::from_ymd_opt(2025, 6, 1);
+
+        assert_eq!(uid, credit_uid(&Credit::Cast(Cast::Movie(cast.clone()))));
+        assert_ne!(guid, credit_guid(&Credit::Cast(Cast::Movie(cast))));
+    }
+
+    #[test]
+    fn test_merge_credits() {
+        let shared = init_movie_cast();
+        let mut other_character = init_movie_cast();
+        other_character.character = Some("Christian Wolff".to_string());
+
+        let mut unique = init_movie_cast();
+        unique.id = 1;
+
+        let (people, items) = merge_credits(vec![
+            (
+                init_person(19498, "Jon Bernthal"),
+                vec![Credit::Cast(Cast::Movie(shared))],
+            ),
+            (
+                init_person(1892, "Ben Affleck"),
+                vec![
+                    Credit::Cast(Cast::Movie(other_character)),
+                    Credit::Cast(Cast::Movie(unique)),
+                ],
+            ),
+        ]);
+
+        assert_eq!(people.len(), 2);
+        assert_eq!(items.len(), 2);
+
+        assert_eq!(
+            item_roles(&items[0], "<br>", Messages::default()),
+            "People: Jon Bernthal (Braxton Wolff), Ben Affleck (Christian Wolff)"
+        );
+        assert_eq!(
+            item_roles(&items[1], "<br>", Messages::default()),
+            "People: Ben Affleck (Braxton Wolff)"
+        );
+        assert_eq!(
+            item_categories(&items[0], Messages::default()),
+            vec!["Movie", "Jon Bernthal", "Ben Affleck"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_history() {
+        let store = Arc::new(Store::in_memory().unwrap());
+
+        let known = init_movie_cast();
+        let mut announced = init_movie_cast();
+        announced.id = 1;
+
+        let known_uid = credit_uid(&Credit::Cast(Cast::Movie(known.clone())));
+        let first_seen = build_date() - TimeDelta::days(7);
+        store.first_seen(&[known_uid], first_seen).unwrap();
+
+        let mut items = vec![
+            FeedItem::from(Credit::Cast(Cast::Movie(known))),
+            FeedItem::from(Credit::Cast(Cast::Movie(announced))),
+        ];
+
+        record_history(&store, &mut items, None).await;
+
+        assert_eq!(items[0].first_seen, Some(first_seen));
+        assert_eq!(items[1].first_seen, Some(build_date()));
+
+        let query = QueryArgs {
+            release_status: ReleaseStatus::All,
+            sort_by: SortBy::FirstSeen,
+            ..QueryArgs::default()
+        };
+        let items = select_items(items, &query, FeedFormat::Rss);
+
+        assert_eq!(items[0].credit.id(), 1);
+        assert_eq!(items[1].first_seen, Some(first_seen));
+    }
+
+    #[tokio::test]
+    async fn test_record_history_first_seen_retained() {
+        let store = Arc::new(Store::in_memory().unwrap());
+
+        let original = init_movie_cast();
+        let first_seen = build_date() - TimeDelta::days(7);
+        store
+            .first_seen(
+                &[credit_uid(&Credit::Cast(Cast::Movie(original.clone())))],
+                first_seen,
+            )
+            .unwrap();
+
+        let mut changed = original;
+        changed.title = "Der Accountant²".to_string();
+        changed.release_date = NaiveDate::from_ymd_opt(2025, 6, 1);
+
+        let language = Language::from_str("de-DE").unwrap();
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(changed)))];
+        record_history(&store, &mut items, Some(&language)).await;
+
+        assert_eq!(items[0].first_seen, Some(first_seen));
+    }
+
+    #[tokio::test]
+    async fn test_record_history_changes() {
+        let store = Arc::new(Store::in_memory().unwrap());
+
+        let original = init_movie_cast();
+        let mut rescheduled = init_movie_cast();
+        rescheduled.release_date = NaiveDate::from_ymd_opt(2025, 6, 1);
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(original)))];
+        record_history(&store, &mut items, None).await;
+        assert!(items[0].change.is_none());
+        assert!(item_changes(&items[0], Messages::default()).is_empty());
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(rescheduled)))];
+        record_history(&store, &mut items, None).await;
+
+        assert_eq!(
+            item_changes(&items[0], Messages::default()),
+            vec!["Release date changed: 23-Apr-2025 → 01-Jun-2025"]
+        );
+        assert!(
+            item_description(&items[0], Messages::default())
+                .starts_with("<p>Release date changed: 23-Apr-2025 → 01-Jun-2025</p>")
+        );
+        assert!(
+            item_plain_description(&items[0], Messages::default())
+                .starts_with("Release date changed: 23-Apr-2025 → 01-Jun-2025\n\n")
+        );
+
+        let metadata = FeedMetadata::people(String::new(), None, Vec::new(), Messages::default());
+        let feed = json_feed(metadata, items, &ImageUrlBuilder::default());
+        assert_eq!(feed.items[0].date_modified, Some(build_date()));
+    }
+
+    #[test]
+    fn test_atom_entry_updated() {
+        let first_seen = build_date() - TimeDelta::days(7);
+        let detected = build_date() - TimeDelta::days(1);
+
+        let mut items = vec![
+            FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast()))),
+            FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast()))),
+            FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast()))),
+        ];
+        items[1].first_seen = Some(first_seen);
+        items[2].first_seen = Some(first_seen);
+        items[2].change = Some(CreditChange {
+            previous: CreditSnapshot {
+                title: "The Accountant 2".to_string(),
+                release_date: None,
+            },
+            detected,
+        });
+
+        let metadata = FeedMetadata::people(String::new(), None, Vec::new(), Messages::default());
+        let atom = atom_feed(metadata, items, &ImageUrlBuilder::default(), build_date());
+        let updated = atom
+            .feed()
+            .entries()
+            .iter()
+            .map(|entry| *entry.updated())
+            .collect_vec();
+
+        assert_eq!(
+            updated,
+            vec![
+                build_date().fixed_offset(),
+                first_seen.fixed_offset(),
+                detected.fixed_offset()
+            ]
+        );
+        assert_eq!(*atom.feed().updated(), build_date().fixed_offset());
+    }
+
+    #[tokio::test]
+    async fn test_record_history_localised() {
+        let store = Arc::new(Store::in_memory().unwrap());
+        let language = Language::from_str("de-DE").unwrap();
+
+        let mut localised = init_movie_cast();
+        localised.title = "The Accountant 2".to_string();
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())))];
+        record_history(&store, &mut items, None).await;
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(localised)))];
+        record_history(&store, &mut items, Some(&language)).await;
+        assert!(items[0].change.is_none());
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())))];
+        record_history(&store, &mut items, None).await;
+        assert!(items[0].change.is_none());
+    }
+
+    #[test]
+    fn test_select_items_ics_excludes_undated() {
+        let mut undated = init_movie_cast();
+        undated.id = 1;
+        undated.release_date = None;
+
+        let mut dated = init_movie_cast();
+        dated.id = 2;
+
+        let items = || {
+            vec![
+                FeedItem::from(Credit::Cast(Cast::Movie(undated.clone()))),
+                FeedItem::from(Credit::Cast(Cast::Movie(dated.clone()))),
+            ]
+        };
+
+        let query = QueryArgs {
+            release_status: ReleaseStatus::All,
+            sort_order: SortReleaseDates::Descending,
+            size: Size::try_from(1).unwrap(),
+            ..QueryArgs::default()
+        };
+
+        let selected = select_items(items(), &query, FeedFormat::Rss);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].credit.id(), 1);
+
+        let selected = select_items(items(), &query, FeedFormat::Ics);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].credit.id(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_regional_release_dates() {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_movie_release_dates(&mut server, 870028).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())))];
+
+        regional_release_dates(&tmdb, &mut items, &QueryArgs::default()).await;
+        assert_eq!(items[0].release_date, NaiveDate::from_ymd_opt(2025, 4, 23));
+
+        let query = QueryArgs {
+            region: Some(Region::from_str("GB").unwrap()),
+            release_type: Some(ReleaseType::Digital),
+            ..QueryArgs::default()
+        };
+        regional_release_dates(&tmdb, &mut items, &query).await;
+        assert_eq!(items[0].release_date, NaiveDate::from_ymd_opt(2025, 6, 18));
+        assert!(
+            item_description(&items[0], Messages::default()).contains("Release Date: 18-Jun-2025")
+        );
+
+        let query = QueryArgs {
+            region: Some(Region::from_str("GB").unwrap()),
+            release_type: Some(ReleaseType::Physical),
+            ..QueryArgs::default()
+        };
+        regional_release_dates(&tmdb, &mut items, &query).await;
+        assert_eq!(items[0].release_date, None);
+
+        assert!(mock.matched());
+    }
+
+    #[tokio::test]
+    async fn test_regional_release_dates_default_region() {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_movie_release_dates(&mut server, 870028).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+        tmdb.set_localisation(Localisation {
+            language: None,
+            region: Some(Region::from_str("GB").unwrap()),
+        });
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())))];
+
+        regional_release_dates(&tmdb, &mut items, &QueryArgs::default()).await;
+        assert_eq!(items[0].release_date, NaiveDate::from_ymd_opt(2025, 4, 25));
+
+        let query = QueryArgs {
+            release_type: Some(ReleaseType::Digital),
+            ..QueryArgs::default()
+        };
+        regional_release_dates(&tmdb, &mut items, &query).await;
+        assert_eq!(items[0].release_date, NaiveDate::from_ymd_opt(2025, 6, 18));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_regional_release_dates_candidates() {
+        const UPCOMING_MOVIES: usize = 20;
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_movie_release_dates(&mut server, 870028)
+            .await
+            .expect(2);
+        let upcoming_mock = server
+            .mock(
+                "GET",
+                Matcher::Regex(r"^/3/movie/\d{1,2}/release_dates$".to_string()),
+            )
+            .with_status(404)
+            .expect(UPCOMING_MOVIES)
+            .create_async()
+            .await;
+
+        let mut items = vec![FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())))];
+        for id in 1..=UPCOMING_MOVIES {
+            let mut cast = init_movie_cast();
+            cast.id = id;
+            cast.release_date = NaiveDate::from_ymd_opt(2030, 1, 1);
+            items.push(FeedItem::from(Credit::Cast(Cast::Movie(cast))));
+        }
+
+        // upcoming movies cannot release within the default four weeks
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let query = QueryArgs {
+            region: Some(Region::from_str("GB").unwrap()),
+            ..QueryArgs::default()
+        };
+        regional_release_dates(&tmdb, &mut items, &query).await;
+        assert_eq!(items[1].release_date, NaiveDate::from_ymd_opt(2030, 1, 1));
+
+        // whereas any movie may lack a regional release
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let query = QueryArgs {
+            region: Some(Region::from_str("GB").unwrap()),
+            release_status: ReleaseStatus::NoReleaseDate,
+            ..QueryArgs::default()
+        };
+        regional_release_dates(&tmdb, &mut items, &query).await;
+
+        mock.assert();
+        upcoming_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_item_poster() {
+        let mut server = start_mock_tmdb_api().await;
+        let _mock = mock_get_configuration(&mut server).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let images = image_url_builder(&tmdb).await;
+        let item = FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())));
+
+        assert_eq!(
+            item_poster(&item, &images).unwrap().as_str(),
+            "https://image.tmdb.org/t/p/w342/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg"
+        );
+
+        let mut cast = init_movie_cast();
+        cast.poster_path = None;
+        let item = FeedItem::from(Credit::Cast(Cast::Movie(cast)));
+
+        assert_eq!(item_poster(&item, &images), None);
+    }
+
+    #[test]
+    fn test_item_extensions() {
+        let metadata = FeedMetadata::people(
+            String::new(),
+            None,
+            vec![FeedPerson::from(&init_person(19498, "Jon Bernthal"))],
+            Messages::default(),
+        );
+        let item = FeedItem::from(Credit::Cast(Cast::Movie(init_movie_cast())));
+
+        let media = item_media(&item, &metadata, &ImageUrlBuilder::default());
+        let credit = &media["credit"][0];
+        assert_eq!(credit.name(), "media:credit");
+        assert_eq!(credit.attrs()["role"], "actor");
+        assert_eq!(credit.value(), Some("Jon Bernthal"));
+        assert_eq!(
+            media["content"][0].attrs()["url"],
+            "https://image.tmdb.org/t/p/w342/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg"
+        );
+
+        let dublin_core = item_dublin_core(&item, &metadata);
+        assert_eq!(dublin_core.creators(), ["Jon Bernthal"]);
+        assert_eq!(dublin_core.dates(), ["2025-04-23"]);
+    }
+
+    #[test]
+    fn test_people_title() {
+        let people = ["A", "B", "C", "D", "E"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, name)| FeedPerson::from(&init_person(id as i32, name)))
+            .collect_vec();
+
+        let messages = Messages::default();
+
+        let metadata = FeedMetadata::people(String::new(), None, people[..2].to_vec(), messages);
+        assert_eq!(metadata.title, "A, B - Combined Credits");
+
+        let metadata = FeedMetadata::people(String::new(), None, people[..4].to_vec(), messages);
+        assert_eq!(metadata.title, "A, B, C and 1 other - Combined Credits");
+
+        let metadata = FeedMetadata::people(String::new(), None, people.clone(), messages);
+        assert_eq!(metadata.title, "A, B, C and 2 others - Combined Credits");
+
+        let metadata = FeedMetadata::people(String::new(), Some("directors"), people, messages);
+        assert_eq!(metadata.title, "directors - Combined Credits");
+    }
+}
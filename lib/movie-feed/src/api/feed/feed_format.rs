@@ -0,0 +1,145 @@
+use axum::http::HeaderMap;
+use axum::http::header::ACCEPT;
+use serde::Deserialize;
+
+#[derive(Deserialize, Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "lowercase")]
+/// The syndication format used to render the feed
+pub(crate) enum FeedFormat {
+    /// [RSS 2.0](https://www.rssboard.org/rss-specification)
+    #[default]
+    Rss,
+    /// [Atom 1.0](https://www.rfc-editor.org/rfc/rfc4287)
+    Atom,
+    /// [JSON Feed 1.1](https://www.jsonfeed.org/version/1.1/)
+    Json,
+    /// [iCalendar](https://www.rfc-editor.org/rfc/rfc5545), containing an all-day event for each
+    /// credit with a release date
+    Ics,
+}
+
+impl FeedFormat {
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type {
+            "application/rss+xml" => Some(Self::Rss),
+            "application/atom+xml" => Some(Self::Atom),
+            "application/feed+json" | "application/json" => Some(Self::Json),
+            "text/calendar" => Some(Self::Ics),
+            _ => None,
+        }
+    }
+
+    /// Selects the most preferred format listed within the `Accept` header, ties are broken by
+    /// the order in which the media types appear.
+    ///
+    /// Returns [`None`] if the header is absent or does not list a supported format.
+    pub(super) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let mut preferred: Option<(Self, f32)> = None;
+
+        for value in headers.get_all(ACCEPT) {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+
+            for media_range in value.split(',') {
+                let mut params = media_range.split(';').map(str::trim);
+
+                let Some(format) = params
+                    .next()
+                    .map(str::to_ascii_lowercase)
+                    .and_then(|media_type| Self::from_media_type(media_type.as_str()))
+                else {
+                    continue;
+                };
+
+                let quality = params
+                    .find_map(|param| param.strip_prefix("q="))
+                    .and_then(|quality| quality.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                if quality <= 0.0 {
+                    continue;
+                }
+
+                match preferred {
+                    Some((_, preferred_quality)) if preferred_quality >= quality => (),
+                    _ => preferred = Some((format, quality)),
+                }
+            }
+        }
+
+        preferred.map(|(format, _)| format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(accept: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(accept));
+        headers
+    }
+
+    #[test]
+    fn test_no_accept_header() {
+        assert_eq!(FeedFormat::from_headers(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(FeedFormat::from_headers(&headers("text/html, */*")), None);
+    }
+
+    #[test]
+    fn test_single() {
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/atom+xml")),
+            Some(FeedFormat::Atom)
+        );
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/rss+xml")),
+            Some(FeedFormat::Rss)
+        );
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/feed+json")),
+            Some(FeedFormat::Json)
+        );
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/json")),
+            Some(FeedFormat::Json)
+        );
+        assert_eq!(
+            FeedFormat::from_headers(&headers("text/calendar")),
+            Some(FeedFormat::Ics)
+        );
+    }
+
+    #[test]
+    fn test_order() {
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/atom+xml, application/rss+xml")),
+            Some(FeedFormat::Atom)
+        );
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/rss+xml, application/atom+xml")),
+            Some(FeedFormat::Rss)
+        );
+    }
+
+    #[test]
+    fn test_quality() {
+        assert_eq!(
+            FeedFormat::from_headers(&headers(
+                "application/rss+xml;q=0.5, application/atom+xml;q=0.9, */*;q=0.1"
+            )),
+            Some(FeedFormat::Atom)
+        );
+        assert_eq!(
+            FeedFormat::from_headers(&headers("application/atom+xml;q=0")),
+            None
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use chrono::{Locale, NaiveDate};
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use std::sync::LazyLock;
+use tmdb::models::v3::genres::Genre;
+use tmdb::models::v3::language::Language;
+use tmdb::models::v3::media_type::MediaType;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+/// The format of dates within feeds, e.g. `23-Apr-2025`
+const DATE_FORMAT: &str = "%d-%b-%Y";
+
+/// The [Fluent](https://projectfluent.org/) message catalogue of each supported language, the
+/// first of which is the default and used for any messages missing from the others
+const CATALOGUES: [(&str, Locale, &str); 3] = [
+    ("en", Locale::en_GB, include_str!("../../../locales/en.ftl")),
+    ("de", Locale::de_DE, include_str!("../../../locales/de.ftl")),
+    ("fr", Locale::fr_FR, include_str!("../../../locales/fr.ftl")),
+];
+
+struct Catalogue {
+    language: &'static str,
+    date_locale: Locale,
+    bundle: FluentBundle<FluentResource>,
+}
+
+static BUNDLES: LazyLock<Vec<Catalogue>> = LazyLock::new(|| {
+    CATALOGUES
+        .into_iter()
+        .map(|(language, date_locale, source)| {
+            let resource = FluentResource::try_new(source.to_string()).expect("valid ftl, tested");
+            let language_id: LanguageIdentifier = language.parse().expect("valid str, tested");
+
+            let mut bundle = FluentBundle::new_concurrent(vec![language_id]);
+            bundle.set_use_isolating(false);
+            bundle
+                .add_resource(resource)
+                .expect("unique message ids, tested");
+
+            Catalogue {
+                language,
+                date_locale,
+                bundle,
+            }
+        })
+        .collect()
+});
+
+/// The text of a feed which is not provided by TMDB, e.g. labels and genre names, in the language
+/// requested by the feed
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Messages {
+    /// Index of the catalogue within [`CATALOGUES`], the default being the first
+    catalogue: usize,
+}
+
+impl Messages {
+    /// The messages of `language`, ignoring any region, falling back to the default catalogue
+    /// should the language not be supported
+    pub(crate) fn new(language: Option<&Language>) -> Self {
+        let Some(language) = language else {
+            return Self::default();
+        };
+
+        let primary = language.as_str().split('-').next().unwrap_or_default();
+
+        CATALOGUES
+            .iter()
+            .position(|(catalogue, _, _)| *catalogue == primary)
+            .map(|catalogue| Self { catalogue })
+            .unwrap_or_default()
+    }
+
+    fn catalogue(&self) -> &'static Catalogue {
+        &BUNDLES[self.catalogue]
+    }
+
+    /// Formats the message `id`, see [`Messages::format`]
+    pub(crate) fn get(&self, id: &str) -> String {
+        self.format(id, &[])
+    }
+
+    /// Formats the message `id` with `args`, falling back to the default catalogue should the
+    /// message be missing and to `id` itself should it be missing from both
+    pub(crate) fn format(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        self.try_format(id, args).unwrap_or_else(|| id.to_string())
+    }
+
+    fn try_format(&self, id: &str, args: &[(&str, FluentValue)]) -> Option<String> {
+        let args = args
+            .iter()
+            .map(|(key, value)| (*key, value.clone()))
+            .collect::<FluentArgs>();
+
+        [self.catalogue(), &BUNDLES[0]]
+            .into_iter()
+            .find_map(|catalogue| {
+                let pattern = catalogue.bundle.get_message(id)?.value()?;
+                let mut errors = Vec::new();
+
+                let message = catalogue
+                    .bundle
+                    .format_pattern(pattern, Some(&args), &mut errors);
+
+                if !errors.is_empty() {
+                    warn!(
+                        "unable to format message {id} of {}: {errors:?}",
+                        catalogue.language
+                    );
+                }
+
+                Some(message.into_owned())
+            })
+    }
+
+    /// The name of a genre, falling back to the name provided by TMDB for those not within the
+    /// catalogue
+    pub(crate) fn genre(&self, genre: &dyn Genre) -> String {
+        self.try_format(format!("genre-{}", *genre.id()).as_str(), &[])
+            .unwrap_or_else(|| genre.name().to_string())
+    }
+
+    pub(crate) fn media_type(&self, media_type: &MediaType) -> String {
+        match media_type {
+            MediaType::Movie => self.get("media-type-movie"),
+            MediaType::Tv => self.get("media-type-tv"),
+            _ => media_type.to_string(),
+        }
+    }
+
+    /// A release date, or `TBA` should it be unknown
+    pub(crate) fn date(&self, date: Option<&NaiveDate>) -> String {
+        match date {
+            None => self.get("tba"),
+            Some(date) => date
+                .format_localized(DATE_FORMAT, self.catalogue().date_locale)
+                .to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluent_syntax::ast::Entry;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+    use tmdb::models::v3::genres::{MovieGenre, TvGenre};
+
+    fn message_ids(source: &str) -> HashSet<String> {
+        FluentResource::try_new(source.to_string())
+            .unwrap()
+            .entries()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogues_complete() {
+        let default = message_ids(CATALOGUES[0].2);
+
+        for (language, _, source) in CATALOGUES {
+            assert_eq!(message_ids(source), default, "{language} catalogue");
+        }
+
+        assert_eq!(BUNDLES.len(), CATALOGUES.len());
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Messages::new(None), Messages::default());
+        assert_eq!(
+            Messages::new(Some(&Language::from_str("de-AT").unwrap()))
+                .catalogue()
+                .language,
+            "de"
+        );
+        assert_eq!(
+            Messages::new(Some(&Language::from_str("ja").unwrap())),
+            Messages::default()
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let messages = Messages::default();
+
+        assert_eq!(
+            messages.format("feed-title", &[("names", FluentValue::from("A, B"))]),
+            "A, B - Combined Credits"
+        );
+        assert_eq!(
+            messages.format(
+                "feed-title-others",
+                &[
+                    ("names", FluentValue::from("A")),
+                    ("others", FluentValue::from(1))
+                ]
+            ),
+            "A and 1 other - Combined Credits"
+        );
+        assert_eq!(messages.get("missing-message"), "missing-message");
+    }
+
+    #[test]
+    fn test_genre() {
+        let messages = Messages::new(Some(&Language::from_str("de").unwrap()));
+
+        assert_eq!(messages.genre(&MovieGenre::Comedy), "Komödie");
+        assert_eq!(messages.genre(&TvGenre::Comedy), "Komödie");
+        assert_eq!(messages.genre(&MovieGenre::Unknown(1)), "Unknown Genre");
+
+        for genre in [MovieGenre::Action, MovieGenre::ScienceFiction] {
+            assert_eq!(Messages::default().genre(&genre), genre.name());
+        }
+    }
+
+    #[test]
+    fn test_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 23);
+        let german = Messages::new(Some(&Language::from_str("de-DE").unwrap()));
+
+        assert_eq!(Messages::default().date(date.as_ref()), "23-Mar-2025");
+        assert_eq!(german.date(date.as_ref()), "23-Mär-2025");
+        assert_eq!(german.date(None), "Noch offen");
+    }
+}
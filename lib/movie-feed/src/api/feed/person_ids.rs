@@ -0,0 +1,118 @@
+use itertools::Itertools;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer};
+use std::num::ParseIntError;
+use std::ops::Deref;
+use thiserror::Error;
+
+const MAX_PEOPLE: usize = 25;
+
+/// A non-empty, de-duplicated list of TMDB person ids, deserialised from a comma separated list
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct PersonIds(Vec<i32>);
+
+impl Deref for PersonIds {
+    type Target = [i32];
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_slice()
+    }
+}
+
+impl TryFrom<Vec<i32>> for PersonIds {
+    type Error = PersonIdsError;
+
+    fn try_from(ids: Vec<i32>) -> Result<Self, Self::Error> {
+        let ids = ids.into_iter().unique().collect_vec();
+
+        if ids.is_empty() {
+            Err(PersonIdsError::Empty)
+        } else if ids.len() > MAX_PEOPLE {
+            Err(PersonIdsError::ExceedsMaxPeople)
+        } else {
+            Ok(Self(ids))
+        }
+    }
+}
+
+impl TryFrom<&str> for PersonIds {
+    type Error = PersonIdsError;
+
+    fn try_from(ids: &str) -> Result<Self, Self::Error> {
+        ids.split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::parse::<i32>)
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+    }
+}
+
+impl<'de> Deserialize<'de> for PersonIds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ids = String::deserialize(deserializer)?;
+        PersonIds::try_from(ids.as_str()).map_err(DeError::custom)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub(crate) enum PersonIdsError {
+    #[error("at least one person id must be provided")]
+    Empty,
+    #[error("number of person ids must not exceed 25")]
+    ExceedsMaxPeople,
+    #[error(transparent)]
+    ParseInt(#[from] ParseIntError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let ids = PersonIds::try_from("19498,956").unwrap();
+        assert_eq!(ids.as_ref(), &[19498, 956]);
+    }
+
+    #[test]
+    fn test_parse_whitespace_and_trailing_comma() {
+        let ids = PersonIds::try_from(" 19498, 956 ,").unwrap();
+        assert_eq!(ids.as_ref(), &[19498, 956]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let ids = PersonIds::try_from("19498,956,19498").unwrap();
+        assert_eq!(ids.as_ref(), &[19498, 956]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(PersonIds::try_from(""), Err(PersonIdsError::Empty));
+        assert_eq!(PersonIds::try_from(","), Err(PersonIdsError::Empty));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            PersonIds::try_from("19498,abc"),
+            Err(PersonIdsError::ParseInt(_))
+        ));
+    }
+
+    #[test]
+    fn test_exceeding_max() {
+        let ids = (0..=MAX_PEOPLE as i32).collect_vec();
+        assert_eq!(
+            PersonIds::try_from(ids),
+            Err(PersonIdsError::ExceedsMaxPeople)
+        );
+
+        let ids = (0..MAX_PEOPLE as i32).collect_vec();
+        assert!(PersonIds::try_from(ids).is_ok());
+    }
+}
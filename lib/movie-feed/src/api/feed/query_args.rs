@@ -0,0 +1,448 @@
+use crate::api::feed::feed_format::FeedFormat;
+use crate::api::feed::release_status::ReleaseStatus;
+use crate::api::feed::release_status::deserialize_release_status;
+use crate::api::feed::size::Size;
+use crate::api::feed::sort_order::{SortBy, SortReleaseDates};
+use axum::http::HeaderMap;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
+use tmdb::localisation::Localisation;
+use tmdb::models::v3::language::Language;
+use tmdb::models::v3::region::Region;
+use tmdb::models::v3::release_dates::ReleaseType;
+
+#[derive(Deserialize, Default, Debug, Eq, PartialEq)]
+pub(crate) struct QueryArgs {
+    #[serde(default)]
+    /// Number of credits to return
+    pub(crate) size: Size,
+    #[serde(flatten, deserialize_with = "deserialize_release_status")]
+    /// The release status of the credits to return
+    pub(crate) release_status: ReleaseStatus,
+    #[serde(default)]
+    pub(crate) sort_order: SortReleaseDates,
+    #[serde(default)]
+    /// The date by which the credits are sorted
+    pub(crate) sort_by: SortBy,
+    #[serde(default)]
+    /// The format of the feed, takes precedence over the `Accept` header
+    pub(crate) format: Option<FeedFormat>,
+    #[serde(default)]
+    /// The region whose release dates are used in place of the primary release date of movies
+    pub(crate) region: Option<Region>,
+    #[serde(default, deserialize_with = "deserialize_release_type")]
+    /// The type of regional release used, only applicable alongside `region`
+    pub(crate) release_type: Option<ReleaseType>,
+    #[serde(default)]
+    /// The language of the titles and overviews of credits, e.g. `de-DE`
+    pub(crate) lang: Option<Language>,
+}
+
+fn deserialize_release_type<'de, D>(deserializer: D) -> Result<Option<ReleaseType>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let str: &str = Deserialize::deserialize(deserializer)?;
+
+    ReleaseType::from_str(str)
+        .map(Some)
+        .map_err(DeError::custom)
+}
+
+impl QueryArgs {
+    /// Deserialises the query string, using the arguments within `defaults` for any which have
+    /// not been provided
+    pub(crate) fn with_defaults(
+        defaults: &str,
+        query: Option<&str>,
+    ) -> Result<Self, serde_urlencoded::de::Error> {
+        let query: Vec<(String, String)> = serde_urlencoded::from_str(query.unwrap_or_default())?;
+        let defaults: Vec<(String, String)> = serde_urlencoded::from_str(defaults)?;
+
+        let mut merged = defaults
+            .into_iter()
+            .filter(|(default_key, _)| !query.iter().any(|(key, _)| key == default_key))
+            .collect::<Vec<_>>();
+
+        merged.extend(query);
+
+        let merged = serde_urlencoded::to_string(merged).map_err(DeError::custom)?;
+
+        serde_urlencoded::from_str(merged.as_str())
+    }
+
+    /// The requested format, falling back to the `Accept` header and then the default format
+    pub(crate) fn feed_format(&self, headers: &HeaderMap) -> FeedFormat {
+        self.format
+            .or_else(|| FeedFormat::from_headers(headers))
+            .unwrap_or_default()
+    }
+
+    /// The localisation of requests to TMDB, the region is excluded as it is only used for
+    /// selecting regional release dates, see [`QueryArgs::regional_release`]
+    pub(crate) fn localisation(&self) -> Localisation {
+        Localisation {
+            language: self.lang.clone(),
+            region: None,
+        }
+    }
+
+    /// The region and type of release whose date should be used for movies, falling back to
+    /// `default_region` should the query not provide one, and defaulting to a theatrical release
+    /// should only the region be provided
+    pub(crate) fn regional_release<'a>(
+        &'a self,
+        default_region: Option<&'a Region>,
+    ) -> Option<(&'a Region, ReleaseType)> {
+        let region = self.region.as_ref().or(default_region)?;
+
+        Some((region, self.release_type.unwrap_or(ReleaseType::Theatrical)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::feed::release_status::ReleaseStatusError;
+    use axum::extract::Query;
+    use axum::http::Uri;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_query_args_default_deserialisation() {
+        let uri = Uri::from_static(r##"https://example.com"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(query.unwrap().0, QueryArgs::default());
+    }
+
+    #[test]
+    fn test_query_args_size_deserialisation() {
+        let uri = Uri::from_static(r##"https://example.com?size=10"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                size: Size::try_from(10).unwrap(),
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?size=-5"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+        assert_eq!(
+            query.err().unwrap().to_string(),
+            "Failed to deserialize query string: size: invalid digit found in string"
+        );
+
+        let uri = Uri::from_str(
+            format!("https://example.com?size={}", Size::MAX_SIZE.get() + 1).as_str(),
+        )
+        .unwrap();
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+        assert_eq!(
+            query.err().unwrap().to_string(),
+            "Failed to deserialize query string: size: size must not exceed the max size"
+        );
+
+        let uri = Uri::from_static("https://example.com?size=0");
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+        assert_eq!(
+            query.err().unwrap().to_string(),
+            "Failed to deserialize query string: size: invalid value: integer `0`, expected a nonzero usize"
+        );
+    }
+
+    #[test]
+    fn test_query_args_format_deserialisation() {
+        let uri = Uri::from_static(r##"https://example.com?format=atom"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                format: Some(FeedFormat::Atom),
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?format=rss"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                format: Some(FeedFormat::Rss),
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?format=json"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                format: Some(FeedFormat::Json),
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?format=ics"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                format: Some(FeedFormat::Ics),
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?format=xml"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+    }
+
+    #[test]
+    fn test_query_args_release_status_deserialisation() {
+        // Unreleased
+        let uri = Uri::from_static(r##"https://example.com?release_status=Unreleased"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::Unreleased {
+            max_time_until_release: None,
+        };
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(
+            r##"https://example.com?release_status=Unreleased&max_time_until_release=5m"##,
+        );
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::Unreleased {
+            max_time_until_release: Some(TimeDelta::minutes(5)),
+        };
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        // Released
+        let uri = Uri::from_static(r##"https://example.com?release_status=Released"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::Released {
+            max_age: None,
+            min_age: None,
+        };
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(
+            r##"https://example.com?release_status=Released&max_age=5h&min_age=5m"##,
+        );
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::Released {
+            max_age: Some(TimeDelta::hours(5)),
+            min_age: Some(TimeDelta::minutes(5)),
+        };
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(
+            r##"https://example.com?release_status=Released&max_age=1s&min_age=5m"##,
+        );
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap_err().to_string(),
+            format!(
+                "Failed to deserialize query string: {}",
+                ReleaseStatusError::MaxAgeSmaller.text()
+            )
+        );
+
+        // HasReleaseDate
+        let uri = Uri::from_static(r##"https://example.com?release_status=HasReleaseDate"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::HasReleaseDate {
+            max_time_until_release: None,
+            max_age: None,
+        };
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(
+            r##"https://example.com?release_status=HasReleaseDate&max_time_until_release=52w&max_age=5h"##,
+        );
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::HasReleaseDate {
+            max_time_until_release: Some(TimeDelta::weeks(52)),
+            max_age: Some(TimeDelta::hours(5)),
+        };
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        // NoReleaseDate
+        let uri = Uri::from_static(r##"https://example.com?release_status=NoReleaseDate"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::NoReleaseDate;
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+
+        // All
+        let uri = Uri::from_static(r##"https://example.com?release_status=All"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        let release_status = ReleaseStatus::All;
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                release_status,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_query_args_sort_by_deserialisation() {
+        let uri = Uri::from_static(r##"https://example.com?sort_by=FirstSeen"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert_eq!(
+            query.unwrap().0,
+            QueryArgs {
+                sort_by: SortBy::FirstSeen,
+                ..Default::default()
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?sort_by=Title"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+    }
+
+    #[test]
+    fn test_query_args_lang_deserialisation() {
+        let uri = Uri::from_static(r##"https://example.com?lang=de-de"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri).unwrap().0;
+        assert_eq!(
+            query.localisation(),
+            Localisation {
+                language: Some(Language::from_str("de-DE").unwrap()),
+                region: None,
+            }
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?lang=german"##);
+        assert!(Query::<QueryArgs>::try_from_uri(&uri).is_err());
+    }
+
+    #[test]
+    fn test_query_args_region_deserialisation() {
+        let uri = Uri::from_static(r##"https://example.com?region=gb&release_type=digital"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri).unwrap().0;
+        assert_eq!(
+            query,
+            QueryArgs {
+                region: Some(Region::from_str("GB").unwrap()),
+                release_type: Some(ReleaseType::Digital),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            query.regional_release(None),
+            Some((&Region::from_str("GB").unwrap(), ReleaseType::Digital))
+        );
+        assert_eq!(
+            query.regional_release(Some(&Region::from_str("US").unwrap())),
+            Some((&Region::from_str("GB").unwrap(), ReleaseType::Digital))
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?region=US"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri).unwrap().0;
+        assert_eq!(
+            query.regional_release(None),
+            Some((&Region::from_str("US").unwrap(), ReleaseType::Theatrical))
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?release_type=digital"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri).unwrap().0;
+        assert_eq!(query.regional_release(None), None);
+        assert_eq!(
+            query.regional_release(Some(&Region::from_str("GB").unwrap())),
+            Some((&Region::from_str("GB").unwrap(), ReleaseType::Digital))
+        );
+
+        let uri = Uri::from_static(r##"https://example.com?region=GBR"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+
+        let uri = Uri::from_static(r##"https://example.com?region=GB&release_type=cinema"##);
+        let query = Query::<QueryArgs>::try_from_uri(&uri);
+        assert!(query.is_err());
+    }
+
+    #[test]
+    fn test_query_args_with_defaults() {
+        let query = QueryArgs::with_defaults("size=5&format=atom", None);
+        assert_eq!(
+            query.unwrap(),
+            QueryArgs {
+                size: Size::try_from(5).unwrap(),
+                format: Some(FeedFormat::Atom),
+                ..Default::default()
+            }
+        );
+
+        let query = QueryArgs::with_defaults("size=5&format=atom", Some("size=10"));
+        assert_eq!(
+            query.unwrap(),
+            QueryArgs {
+                size: Size::try_from(10).unwrap(),
+                format: Some(FeedFormat::Atom),
+                ..Default::default()
+            }
+        );
+
+        let query = QueryArgs::with_defaults("", Some("release_status=All"));
+        assert_eq!(
+            query.unwrap(),
+            QueryArgs {
+                release_status: ReleaseStatus::All,
+                ..Default::default()
+            }
+        );
+
+        let query = QueryArgs::with_defaults("size=0", None);
+        assert!(query.is_err());
+    }
+}
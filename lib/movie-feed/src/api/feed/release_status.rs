@@ -1,4 +1,6 @@
-use chrono::{Datelike, NaiveDate, TimeDelta, Utc};
+#[cfg(not(test))]
+use chrono::{Datelike, Utc};
+use chrono::{NaiveDate, TimeDelta};
 use serde::{Deserialize, Deserializer};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
@@ -7,7 +9,7 @@ use thiserror::Error;
 #[derive(Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "release_status")]
 /// Release date constraints used for selecting credits which fall within the defined range
-pub(super) enum ReleaseStatus {
+pub(crate) enum ReleaseStatus {
     /// Unreleased Credits
     ///
     /// # Default
@@ -203,6 +205,29 @@ impl ReleaseStatus {
             Self::All => true,
         }
     }
+
+    /// The latest release date which can satisfy the status, or `None` should release dates be
+    /// unbounded in the future
+    pub(super) fn latest_release_date(&self) -> Option<NaiveDate> {
+        match self {
+            Self::Unreleased {
+                max_time_until_release: Some(max_time_until_release),
+            }
+            | Self::HasReleaseDate {
+                max_time_until_release: Some(max_time_until_release),
+                ..
+            } => Self::date_now().checked_add_signed(*max_time_until_release),
+            Self::Released { min_age, .. } => {
+                let now = Self::date_now();
+
+                match min_age {
+                    Some(min_age) => now.checked_sub_signed(*min_age),
+                    None => Some(now),
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Error)]
@@ -258,9 +283,7 @@ mod tests {
     }
 
     fn init_release_status() -> ReleaseStatusInit {
-        let now = Utc::now();
-        let now = NaiveDate::from_ymd_opt(now.year(), now.month(), now.day())
-            .expect("constructed from Utc::now(), should always be valid");
+        let now = ReleaseStatus::date_now();
 
         ReleaseStatusInit {
             now,
@@ -391,4 +414,51 @@ mod tests {
         assert!(ReleaseStatus::All.check(Some(&now)));
         assert!(ReleaseStatus::All.check(Some(&NaiveDate::MAX)));
     }
+
+    #[test]
+    fn test_release_status_latest_release_date() {
+        let data = init_release_status();
+        let now = data.now;
+
+        assert_eq!(
+            ReleaseStatus::Unreleased {
+                max_time_until_release: Some(TimeDelta::weeks(1)),
+            }
+            .latest_release_date(),
+            Some(data.future_one_week)
+        );
+        assert_eq!(
+            ReleaseStatus::Released {
+                max_age: None,
+                min_age: None,
+            }
+            .latest_release_date(),
+            Some(now)
+        );
+        assert_eq!(
+            ReleaseStatus::Released {
+                max_age: None,
+                min_age: Some(TimeDelta::weeks(1)),
+            }
+            .latest_release_date(),
+            Some(data.past_one_week)
+        );
+        assert_eq!(
+            ReleaseStatus::HasReleaseDate {
+                max_time_until_release: Some(TimeDelta::weeks(1)),
+                max_age: Some(TimeDelta::weeks(6)),
+            }
+            .latest_release_date(),
+            Some(data.future_one_week)
+        );
+        assert_eq!(
+            ReleaseStatus::Unreleased {
+                max_time_until_release: None,
+            }
+            .latest_release_date(),
+            None
+        );
+        assert_eq!(ReleaseStatus::NoReleaseDate.latest_release_date(), None);
+        assert_eq!(ReleaseStatus::All.latest_release_date(), None);
+    }
 }
@@ -14,7 +14,7 @@ const_assert!(
 );
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub(super) struct Size(NonZeroUsize);
+pub(crate) struct Size(NonZeroUsize);
 
 impl Size {
     const DEFAULT_SIZE: NonZeroUsize = NonZeroUsize::new(DEFAULT_SIZE_USIZE).unwrap();
@@ -68,7 +68,7 @@ impl<'de> Deserialize<'de> for Size {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Error)]
-pub(super) enum SizeError {
+pub(crate) enum SizeError {
     #[error("size must not exceed the max size")]
     ExceedsMaxSize,
     #[error(transparent)]
@@ -1,20 +1,25 @@
-use chrono::NaiveDate;
 use serde::Deserialize;
 use std::cmp::Ordering;
 
 #[derive(Deserialize, Default, Debug, Copy, Clone, Eq, PartialEq)]
-pub(super) enum SortReleaseDates {
+pub(crate) enum SortReleaseDates {
     #[default]
     Descending,
     Ascending,
 }
 
+/// The date by which the credits are sorted
+#[derive(Deserialize, Default, Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum SortBy {
+    #[default]
+    ReleaseDate,
+    /// When the credit was first seen, i.e. newly announced credits
+    FirstSeen,
+}
+
 impl SortReleaseDates {
-    pub(super) fn sort_release_date(
-        &self,
-        a: Option<&NaiveDate>,
-        b: Option<&NaiveDate>,
-    ) -> Ordering {
+    /// Orders two dates, those which are absent are treated as being in the future
+    pub(super) fn sort_dates<T: Ord>(&self, a: Option<&T>, b: Option<&T>) -> Ordering {
         match self {
             SortReleaseDates::Descending => match (a, b) {
                 (None, None) => Ordering::Equal,
@@ -35,6 +40,7 @@ impl SortReleaseDates {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::NaiveDate;
 
     fn init() -> Vec<Option<NaiveDate>> {
         vec![
@@ -64,7 +70,7 @@ mod tests {
             NaiveDate::from_ymd_opt(1990, 6, 1),
         ];
 
-        unsorted_dates.sort_by(|a, b| SORT_ORDER.sort_release_date(a.as_ref(), b.as_ref()));
+        unsorted_dates.sort_by(|a, b| SORT_ORDER.sort_dates(a.as_ref(), b.as_ref()));
 
         assert_eq!(unsorted_dates, sorted_dates);
     }
@@ -85,7 +91,7 @@ mod tests {
             None,
         ];
 
-        unsorted_dates.sort_by(|a, b| SORT_ORDER.sort_release_date(a.as_ref(), b.as_ref()));
+        unsorted_dates.sort_by(|a, b| SORT_ORDER.sort_dates(a.as_ref(), b.as_ref()));
 
         assert_eq!(unsorted_dates, sorted_dates);
     }
@@ -0,0 +1,221 @@
+use axum::http::HeaderValue;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::time::Duration;
+
+const PRODUCT_ID: &str = "-//Movie Feed//Movie Feed//EN";
+const MAX_LINE_OCTETS: usize = 75;
+
+/// An [iCalendar](https://www.rfc-editor.org/rfc/rfc5545) document
+pub(super) struct ICalendar {
+    name: String,
+    description: Option<String>,
+    refresh_interval: Duration,
+    events: Vec<ICalendarEvent>,
+}
+
+impl ICalendar {
+    pub(super) fn new(
+        name: String,
+        description: Option<String>,
+        refresh_interval: Duration,
+        events: Vec<ICalendarEvent>,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            refresh_interval,
+            events,
+        }
+    }
+
+    fn write(&self) -> String {
+        let mut calendar = String::new();
+
+        write_line(&mut calendar, "BEGIN", "VCALENDAR");
+        write_line(&mut calendar, "VERSION", "2.0");
+        write_line(&mut calendar, "PRODID", PRODUCT_ID);
+        write_line(&mut calendar, "CALSCALE", "GREGORIAN");
+        write_line(&mut calendar, "METHOD", "PUBLISH");
+        write_line(&mut calendar, "X-WR-CALNAME", escape_text(&self.name));
+
+        if let Some(description) = &self.description {
+            write_line(&mut calendar, "X-WR-CALDESC", escape_text(description));
+        }
+
+        let refresh_interval = format!("PT{}M", self.refresh_interval.as_secs() / 60);
+        write_line(
+            &mut calendar,
+            "REFRESH-INTERVAL;VALUE=DURATION",
+            &refresh_interval,
+        );
+        write_line(&mut calendar, "X-PUBLISHED-TTL", &refresh_interval);
+
+        for event in &self.events {
+            event.write(&mut calendar);
+        }
+
+        write_line(&mut calendar, "END", "VCALENDAR");
+
+        calendar
+    }
+}
+
+/// An all-day `VEVENT`
+pub(super) struct ICalendarEvent {
+    pub(super) uid: String,
+    pub(super) timestamp: DateTime<Utc>,
+    pub(super) date: NaiveDate,
+    pub(super) summary: String,
+    pub(super) description: Option<String>,
+    pub(super) url: String,
+    pub(super) categories: Vec<String>,
+}
+
+impl ICalendarEvent {
+    fn write(&self, calendar: &mut String) {
+        write_line(calendar, "BEGIN", "VEVENT");
+        write_line(calendar, "UID", &self.uid);
+        write_line(
+            calendar,
+            "DTSTAMP",
+            self.timestamp.format("%Y%m%dT%H%M%SZ").to_string(),
+        );
+        write_line(
+            calendar,
+            "DTSTART;VALUE=DATE",
+            self.date.format("%Y%m%d").to_string(),
+        );
+
+        if let Some(end) = self.date.succ_opt() {
+            write_line(
+                calendar,
+                "DTEND;VALUE=DATE",
+                end.format("%Y%m%d").to_string(),
+            );
+        }
+
+        write_line(calendar, "SUMMARY", escape_text(&self.summary));
+
+        if let Some(description) = &self.description {
+            write_line(calendar, "DESCRIPTION", escape_text(description));
+        }
+
+        write_line(calendar, "URL", &self.url);
+
+        if !self.categories.is_empty() {
+            let categories = self
+                .categories
+                .iter()
+                .map(escape_text)
+                .collect::<Vec<_>>()
+                .join(",");
+
+            write_line(calendar, "CATEGORIES", categories);
+        }
+
+        write_line(calendar, "TRANSP", "TRANSPARENT");
+        write_line(calendar, "END", "VEVENT");
+    }
+}
+
+/// Escapes a `TEXT` property value as per
+/// [RFC 5545 3.3.11](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.11)
+fn escape_text<S: AsRef<str>>(text: S) -> String {
+    let text = text.as_ref();
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => (),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Writes a content line, folding it onto multiple lines should it exceed 75 octets as per
+/// [RFC 5545 3.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.1)
+fn write_line<V: AsRef<str>>(calendar: &mut String, name: &str, value: V) {
+    let line = format!("{name}:{}", value.as_ref());
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            calendar.push_str("\r\n ");
+            octets = 1;
+        }
+
+        calendar.push(c);
+        octets += c.len_utf8();
+    }
+
+    calendar.push_str("\r\n");
+}
+
+impl IntoResponse for ICalendar {
+    fn into_response(self) -> Response {
+        (
+            [(
+                CONTENT_TYPE,
+                HeaderValue::from_static("text/calendar; charset=utf-8"),
+            )],
+            self.write(),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type() {
+        let calendar = ICalendar::new(String::new(), None, Duration::ZERO, Vec::new());
+        let response = calendar.into_response();
+
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE),
+            Some(HeaderValue::from_static("text/calendar; charset=utf-8")).as_ref()
+        );
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(
+            escape_text("a, b; c\\d\r\ne"),
+            r#"a\, b\; c\\d\ne"#.to_string()
+        );
+    }
+
+    #[test]
+    fn test_write_line_folding() {
+        let mut calendar = String::new();
+        write_line(&mut calendar, "SUMMARY", "a".repeat(100));
+
+        let lines = calendar.split("\r\n").collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].len(), MAX_LINE_OCTETS);
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[0].len() + lines[1].len() - 1, "SUMMARY:".len() + 100);
+        assert_eq!(lines[2], "");
+    }
+
+    #[test]
+    fn test_write_line_folding_multibyte() {
+        let mut calendar = String::new();
+        write_line(&mut calendar, "SUMMARY", "²".repeat(100));
+
+        for line in calendar.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+const VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+/// A [JSON Feed 1.1](https://www.jsonfeed.org/version/1.1/) document
+#[derive(Debug, Serialize)]
+pub(super) struct JsonFeed {
+    version: &'static str,
+    pub(super) title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) home_page_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) icon: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) authors: Vec<JsonFeedAuthor>,
+    pub(super) items: Vec<JsonFeedItem>,
+}
+
+impl JsonFeed {
+    pub(super) fn new(title: String) -> Self {
+        Self {
+            version: VERSION,
+            title,
+            home_page_url: None,
+            description: None,
+            icon: None,
+            authors: Vec::new(),
+            items: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct JsonFeedAuthor {
+    pub(super) name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct JsonFeedItem {
+    pub(super) id: String,
+    pub(super) url: String,
+    pub(super) title: String,
+    pub(super) content_html: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) image: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) date_published: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) date_modified: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) authors: Vec<JsonFeedAuthor>,
+    #[serde(rename = "_movie_feed")]
+    pub(super) extension: JsonFeedItemExtension,
+}
+
+/// Custom [extension](https://www.jsonfeed.org/version/1.1/#extensions-a-name-extensions-a)
+/// carrying credit data which has no equivalent JSON Feed field
+#[derive(Debug, Serialize)]
+pub(super) struct JsonFeedItemExtension {
+    pub(super) release_date: Option<NaiveDate>,
+}
+
+impl IntoResponse for JsonFeed {
+    fn into_response(self) -> Response {
+        match serde_json::to_vec_pretty(&self) {
+            Ok(bytes) => (
+                [(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("application/feed+json"),
+                )],
+                bytes,
+            )
+                .into_response(),
+            Err(error) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static(mime::TEXT_PLAIN_UTF_8.as_ref()),
+                )],
+                error.to_string(),
+            )
+                .into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type() {
+        let json_feed = JsonFeed::new(String::new());
+        let response = json_feed.into_response();
+
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE),
+            Some(HeaderValue::from_static("application/feed+json")).as_ref()
+        );
+    }
+
+    #[test]
+    fn test_version() {
+        let json = serde_json::to_value(JsonFeed::new("title".to_string())).unwrap();
+
+        assert_eq!(json["version"], VERSION);
+        assert_eq!(json["title"], "title");
+    }
+}
@@ -0,0 +1,308 @@
+use crate::api::auth::Auth;
+use crate::config::{RateLimitConfig, TokenHash};
+use axum::Extension;
+use axum::extract::{FromRequestParts, Request};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum_client_ip::ClientIp;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Number of tracked clients above which full buckets are discarded
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Identifies the client a bucket belongs to
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+enum ClientKey {
+    Token(TokenHash),
+    Ip(IpAddr),
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Per-client token bucket rate limiter
+pub(crate) struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Mutex<HashMap<ClientKey, Bucket>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn capacity(&self) -> f64 {
+        f64::from(self.config.burst.get())
+    }
+
+    /// Tokens replenished per second
+    fn refill_rate(&self) -> f64 {
+        f64::from(self.config.per_minute.get()) / 60.0
+    }
+
+    /// Takes a token from the client's bucket, returning the duration until a token is available
+    /// should the bucket be empty
+    fn acquire(&self, key: ClientKey, now: Instant) -> Result<(), Duration> {
+        let capacity = self.capacity();
+        let refill_rate = self.refill_rate();
+
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            buckets.retain(|_, bucket| {
+                let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+                bucket.tokens + elapsed * refill_rate < capacity
+            });
+        }
+
+        let bucket = buckets.entry(key).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_rate).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / refill_rate))
+        }
+    }
+}
+
+fn too_many_requests(retry_after: Duration) -> Response {
+    let retry_after = retry_after.as_secs_f64().ceil() as u64;
+
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(RETRY_AFTER, HeaderValue::from(retry_after.max(1)))],
+    )
+        .into_response()
+}
+
+/// Middleware rejecting requests from clients which have exceeded their rate limit with
+/// `429 Too Many Requests`
+pub(super) async fn rate_limit(
+    Extension(rate_limiter): Extension<Arc<RateLimiter>>,
+    Extension(auth): Extension<Arc<Auth>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !rate_limiter.config.enabled {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+
+    let token = auth
+        .authenticate(&parts.headers, parts.uri.query())
+        .map(|user| ClientKey::Token(user.token_sha256.clone()));
+
+    let key = match token {
+        Some(key) => key,
+        None => match ClientIp::from_request_parts(&mut parts, &()).await {
+            Ok(ip) => ClientKey::Ip(ip.0),
+            Err(error) => {
+                debug!("unable to determine client ip, skipping rate limit: {error:?}");
+                return next.run(Request::from_parts(parts, body)).await;
+            }
+        },
+    };
+
+    if let Err(retry_after) = rate_limiter.acquire(key, Instant::now()) {
+        return too_many_requests(retry_after);
+    }
+
+    next.run(Request::from_parts(parts, body)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::routes::routes;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::extract::ConnectInfo;
+    use axum_client_ip::ClientIpSource;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::num::NonZeroU32;
+    use tower::ServiceExt;
+    use tower_http::add_extension::AddExtensionLayer;
+
+    fn init_rate_limiter(burst: u32, per_minute: u32) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            enabled: true,
+            burst: NonZeroU32::new(burst).unwrap(),
+            per_minute: NonZeroU32::new(per_minute).unwrap(),
+        })
+    }
+
+    fn ip(last_octet: u8) -> ClientKey {
+        ClientKey::Ip(IpAddr::V4(Ipv4Addr::new(192, 0, 2, last_octet)))
+    }
+
+    #[test]
+    fn test_burst() {
+        let rate_limiter = init_rate_limiter(3, 60);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(rate_limiter.acquire(ip(1), now).is_ok());
+        }
+
+        assert_eq!(
+            rate_limiter.acquire(ip(1), now),
+            Err(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_default_bulk_refresh() {
+        let rate_limiter = RateLimiter::new(RateLimitConfig::default());
+        let now = Instant::now();
+
+        for _ in 0..40 {
+            assert!(rate_limiter.acquire(ip(1), now).is_ok());
+        }
+        assert!(rate_limiter.acquire(ip(1), now).is_err());
+
+        // the next bulk refresh, 15 minutes later
+        let later = now + Duration::from_secs(15 * 60);
+        for _ in 0..40 {
+            assert!(rate_limiter.acquire(ip(1), later).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_default_polling() {
+        let rate_limiter = RateLimiter::new(RateLimitConfig::default());
+        let now = Instant::now();
+
+        while rate_limiter.acquire(ip(1), now).is_ok() {}
+
+        // polling every 3 seconds for a minute
+        let accepted = (1..=20)
+            .map(|poll| now + Duration::from_secs(3 * poll))
+            .filter(|later| rate_limiter.acquire(ip(1), *later).is_ok())
+            .count();
+
+        assert_eq!(accepted, 10);
+    }
+
+    #[test]
+    fn test_refill() {
+        let rate_limiter = init_rate_limiter(1, 30);
+        let now = Instant::now();
+
+        assert!(rate_limiter.acquire(ip(1), now).is_ok());
+        assert_eq!(
+            rate_limiter.acquire(ip(1), now),
+            Err(Duration::from_secs(2))
+        );
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(
+            rate_limiter.acquire(ip(1), later),
+            Err(Duration::from_secs(1))
+        );
+
+        let later = now + Duration::from_secs(2);
+        assert!(rate_limiter.acquire(ip(1), later).is_ok());
+    }
+
+    #[test]
+    fn test_separate_clients() {
+        let rate_limiter = init_rate_limiter(1, 1);
+        let now = Instant::now();
+
+        assert!(rate_limiter.acquire(ip(1), now).is_ok());
+        assert!(rate_limiter.acquire(ip(1), now).is_err());
+
+        assert!(rate_limiter.acquire(ip(2), now).is_ok());
+        assert!(
+            rate_limiter
+                .acquire(ClientKey::Token(TokenHash::from([0; 32])), now)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_too_many_requests() {
+        let response = too_many_requests(Duration::from_millis(1500));
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(RETRY_AFTER),
+            Some(&HeaderValue::from(2))
+        );
+    }
+
+    fn init_router(rate_limiter: RateLimiter) -> Router {
+        routes()
+            .layer(AddExtensionLayer::new(Arc::new(rate_limiter)))
+            .layer(AddExtensionLayer::new(Arc::new(Auth::new(Vec::new()))))
+            .layer(ClientIpSource::ConnectInfo.into_extension())
+    }
+
+    fn list_request() -> Request {
+        let mut request = Request::builder()
+            .uri("/lists/favourites")
+            .body(Body::empty())
+            .unwrap();
+
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([192, 0, 2, 1], 1234))));
+
+        request
+    }
+
+    #[tokio::test]
+    async fn test_middleware_default_bulk_refresh() {
+        let router = init_router(RateLimiter::new(RateLimitConfig::default()));
+
+        for _ in 0..40 {
+            let response = router.clone().oneshot(list_request()).await.unwrap();
+            assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        }
+
+        let response = router.oneshot(list_request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn test_middleware() {
+        let router = init_router(init_rate_limiter(1, 1));
+
+        let response = router.clone().oneshot(list_request()).await.unwrap();
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let response = router.clone().oneshot(list_request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(RETRY_AFTER),
+            Some(&HeaderValue::from(60))
+        );
+
+        let response = router.oneshot(Request::builder().uri("/ok").body(Body::empty()).unwrap());
+        assert_eq!(response.await.unwrap().status(), StatusCode::OK);
+    }
+}
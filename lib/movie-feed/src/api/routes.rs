@@ -1,10 +1,22 @@
+mod imdb;
+mod lists;
 mod ok;
+mod people;
 mod person;
+mod search;
 
-use axum::Router;
+use crate::api::auth::require_auth;
+use crate::api::rate_limit::rate_limit;
+use axum::{Router, middleware};
 
 pub(super) fn routes() -> Router {
     Router::new()
-        .nest(ok::PATH, ok::router())
         .nest(person::PATH, person::router())
+        .nest(people::PATH, people::router())
+        .nest(imdb::PATH, imdb::router())
+        .nest(lists::PATH, lists::router())
+        .nest(search::PATH, search::router())
+        .route_layer(middleware::from_fn(require_auth))
+        .route_layer(middleware::from_fn(rate_limit))
+        .nest(ok::PATH, ok::router())
 }
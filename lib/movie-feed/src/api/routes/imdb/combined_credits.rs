@@ -0,0 +1,152 @@
+use axum::Router;
+use axum::routing::get;
+
+pub(super) const PATH: &str = "/{imdb_id}/combined_credits";
+
+pub(super) fn router() -> Router {
+    Router::new().route("/", get(get::combined_credits))
+}
+
+mod get {
+    use crate::api::ApiState;
+    use crate::api::feed::person_feed;
+    use crate::api::feed::query_args::QueryArgs;
+    use crate::api::process_result::{ProcessedResponse, process_response};
+    use axum::Extension;
+    use axum::extract::{Path, Query};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use std::sync::Arc;
+    use tmdb::endpoints::v3::find::get as get_find;
+    use tmdb::models::v3::external_source::ExternalSource;
+    use tracing::warn;
+
+    /// Whether `imdb_id` is the IMDb id of a person, e.g. `nm1256532`
+    fn is_person_id(imdb_id: &str) -> bool {
+        imdb_id
+            .strip_prefix("nm")
+            .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    pub(super) async fn combined_credits(
+        Path(imdb_id): Path<String>,
+        api_state: Extension<Arc<ApiState>>,
+        query: Query<QueryArgs>,
+        headers: HeaderMap,
+    ) -> Response {
+        if !is_person_id(imdb_id.as_str()) {
+            return (
+                StatusCode::BAD_REQUEST,
+                "imdb id must be that of a person, e.g. nm1256532",
+            )
+                .into_response();
+        }
+
+        let results = get_find(&api_state.tmdb, imdb_id.as_str(), ExternalSource::ImdbId).await;
+
+        let results = match process_response(results) {
+            ProcessedResponse::Ok(results) => results,
+            ProcessedResponse::Err(error) => {
+                warn!("{error}");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+            ProcessedResponse::Response(response) => return response,
+        };
+
+        let Some(person) = results.person_results.first() else {
+            return (
+                StatusCode::NOT_FOUND,
+                format!("no tmdb person found with the imdb id {imdb_id}"),
+            )
+                .into_response();
+        };
+
+        person_feed(&api_state, person.id, &query, &headers).await
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::store::Store;
+        use std::collections::HashMap;
+        use tmdb::Tmdb;
+        use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+        use tmdb_test_utils::api::v3::find::mock_get_find;
+        use tmdb_test_utils::api::v3::person::combined_credits::mock_get_person_combined_credits;
+        use tmdb_test_utils::api::v3::person::external_ids::mock_get_person_external_ids;
+        use tmdb_test_utils::api::v3::person::mock_get_person_details;
+        use tmdb_test_utils::start_mock_tmdb_api;
+
+        async fn combined_credits_response(imdb_id: &str) -> Response {
+            const PERSON_ID: i32 = 19498;
+
+            let mut server = start_mock_tmdb_api().await;
+            let _find_mock = mock_get_find(&mut server, imdb_id, "imdb_id").await;
+            let _details_mock = mock_get_person_details(&mut server, PERSON_ID).await;
+            let _credits_mock =
+                mock_get_person_combined_credits(&mut server, PERSON_ID.to_string().as_str()).await;
+            let _external_ids_mock = mock_get_person_external_ids(&mut server, PERSON_ID).await;
+            let _configuration_mock = mock_get_configuration(&mut server).await;
+
+            let mut tmdb = Tmdb::default();
+            tmdb.override_api_url(server.url().as_str()).unwrap();
+
+            let api_state = ApiState::new(tmdb, HashMap::new(), Store::in_memory().unwrap());
+
+            super::combined_credits(
+                Path(imdb_id.to_string()),
+                Extension(Arc::new(api_state)),
+                Query(QueryArgs::default()),
+                HeaderMap::new(),
+            )
+            .await
+        }
+
+        #[test]
+        fn test_is_person_id() {
+            assert!(is_person_id("nm1256532"));
+            assert!(!is_person_id("nm"));
+            assert!(!is_person_id("tt0133093"));
+            assert!(!is_person_id("nm12a"));
+        }
+
+        #[tokio::test]
+        async fn test_get_default() {
+            let response = combined_credits_response("nm1256532").await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/default.xml"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_not_found() {
+            let response = combined_credits_response("nm0000000").await;
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        #[tokio::test]
+        async fn test_get_invalid_id() {
+            let api_state =
+                ApiState::new(Tmdb::default(), HashMap::new(), Store::in_memory().unwrap());
+
+            let response = super::combined_credits(
+                Path("tt0133093".to_string()),
+                Extension(Arc::new(api_state)),
+                Query(QueryArgs::default()),
+                HeaderMap::new(),
+            )
+            .await;
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+}
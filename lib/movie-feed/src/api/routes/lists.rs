@@ -0,0 +1,211 @@
+use axum::Router;
+use axum::routing::get;
+
+pub(super) const PATH: &str = "/lists";
+
+pub(super) fn router() -> Router {
+    Router::new().route("/{name}", get(get::list))
+}
+
+mod get {
+    use crate::api::ApiState;
+    use crate::api::feed::messages::Messages;
+    use crate::api::feed::person_ids::PersonIds;
+    use crate::api::feed::query_args::QueryArgs;
+    use crate::api::feed::{
+        FeedMetadata, fetch_people_credits, image_url_builder, merge_credits, record_history,
+        regional_release_dates, render, select_items,
+    };
+    use axum::Extension;
+    use axum::extract::{Path, RawQuery};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use std::sync::Arc;
+    use tracing::warn;
+
+    pub(super) async fn list(
+        Path(name): Path<String>,
+        api_state: Extension<Arc<ApiState>>,
+        RawQuery(query): RawQuery,
+        headers: HeaderMap,
+    ) -> Response {
+        let Some(list) = api_state.lists.get(name.as_str()) else {
+            return StatusCode::NOT_FOUND.into_response();
+        };
+
+        let person_ids = match PersonIds::try_from(list.people.clone()) {
+            Ok(person_ids) => person_ids,
+            Err(error) => {
+                warn!("list {name} is misconfigured: {error}");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+
+        let query = match QueryArgs::with_defaults(list.query.as_str(), query.as_deref()) {
+            Ok(query) => query,
+            Err(error) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!("Failed to deserialize query string: {error}"),
+                )
+                    .into_response();
+            }
+        };
+
+        let localisation = query.localisation();
+
+        let (people_credits, images) = tokio::join!(
+            fetch_people_credits(&api_state.tmdb, &person_ids, &localisation),
+            image_url_builder(&api_state.tmdb),
+        );
+
+        let people_credits = match people_credits {
+            Ok(people_credits) => people_credits,
+            Err(response) => return response,
+        };
+
+        let (people, mut items) = merge_credits(people_credits);
+        record_history(&api_state.store, &mut items, query.lang.as_ref()).await;
+        regional_release_dates(&api_state.tmdb, &mut items, &query).await;
+
+        let format = query.feed_format(&headers);
+        let items = select_items(items, &query, format);
+
+        render(
+            format,
+            FeedMetadata::people(
+                format!("urn:movie-feed:list:{name}"),
+                list.title.as_deref(),
+                people,
+                Messages::new(query.lang.as_ref()),
+            ),
+            items,
+            &images,
+            &headers,
+            &api_state.validators,
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::config::WatchlistConfig;
+        use crate::store::Store;
+        use axum::body::HttpBody;
+        use std::collections::HashMap;
+        use tmdb::Tmdb;
+        use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+        use tmdb_test_utils::api::v3::person::combined_credits::mock_get_person_combined_credits;
+        use tmdb_test_utils::api::v3::person::mock_get_person_details;
+        use tmdb_test_utils::mockito::{Mock, ServerGuard};
+        use tmdb_test_utils::start_mock_tmdb_api;
+
+        const LIST_NAME: &str = "favourites";
+        const PERSON_IDS: [i32; 2] = [19498, 956];
+
+        async fn init(query: &str) -> (ApiState, ServerGuard, Vec<Mock>) {
+            let mut server = start_mock_tmdb_api().await;
+            let mut mocks = Vec::with_capacity(PERSON_IDS.len() * 2 + 1);
+
+            for person_id in PERSON_IDS {
+                mocks.push(mock_get_person_details(&mut server, person_id).await);
+                mocks.push(
+                    mock_get_person_combined_credits(&mut server, person_id.to_string().as_str())
+                        .await,
+                );
+            }
+
+            mocks.push(mock_get_configuration(&mut server).await);
+
+            let mut tmdb = Tmdb::default();
+            tmdb.override_api_url(server.url().as_str()).unwrap();
+
+            let mut lists = HashMap::new();
+            lists.insert(
+                LIST_NAME.to_string(),
+                WatchlistConfig {
+                    people: PERSON_IDS.to_vec(),
+                    title: Some("Favourites".to_string()),
+                    query: query.to_string(),
+                },
+            );
+
+            (
+                ApiState::new(tmdb, lists, Store::in_memory().unwrap()),
+                server,
+                mocks,
+            )
+        }
+
+        async fn list(
+            name: &str,
+            default_query: &str,
+            query: Option<&str>,
+        ) -> (StatusCode, axum::body::Bytes) {
+            let (api_state, _server, _) = init(default_query).await;
+
+            let response = super::list(
+                Path(name.to_string()),
+                Extension(Arc::new(api_state)),
+                RawQuery(query.map(str::to_string)),
+                HeaderMap::new(),
+            )
+            .await;
+
+            let status = response.status();
+            let body = response.into_body();
+
+            let size = {
+                let size = body.size_hint();
+                usize::try_from(size.exact().unwrap_or(size.lower()))
+            }
+            .unwrap();
+
+            (status, axum::body::to_bytes(body, size).await.unwrap())
+        }
+
+        #[tokio::test]
+        async fn test_get_default() {
+            let (status, bytes) = list(LIST_NAME, "", None).await;
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!("../../../tests/assets/api/lists/get/default.xml")
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_default_query() {
+            let (status, bytes) = list(LIST_NAME, "size=5&format=atom", None).await;
+            let feed = String::from_utf8_lossy(bytes.as_ref());
+
+            assert_eq!(status, StatusCode::OK);
+            assert!(feed.contains("<title>Favourites - Combined Credits</title>"));
+            assert_eq!(feed.matches("<entry>").count(), 5);
+        }
+
+        #[tokio::test]
+        async fn test_get_query_overrides_default_query() {
+            let (status, bytes) = list(LIST_NAME, "size=5&format=atom", Some("size=10")).await;
+            let feed = String::from_utf8_lossy(bytes.as_ref());
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(feed.matches("<entry>").count(), 10);
+        }
+
+        #[tokio::test]
+        async fn test_get_invalid_query() {
+            let (status, _) = list(LIST_NAME, "", Some("size=0")).await;
+
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+
+        #[tokio::test]
+        async fn test_get_unknown_list() {
+            let (status, _) = list("unknown", "", None).await;
+
+            assert_eq!(status, StatusCode::NOT_FOUND);
+        }
+    }
+}
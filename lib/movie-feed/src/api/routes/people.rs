@@ -0,0 +1,9 @@
+use axum::Router;
+
+mod combined_credits;
+
+pub(super) const PATH: &str = "/people";
+
+pub(super) fn router() -> Router {
+    Router::new().nest(combined_credits::PATH, combined_credits::router())
+}
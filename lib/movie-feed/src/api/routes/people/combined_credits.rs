@@ -0,0 +1,212 @@
+use axum::Router;
+use axum::routing::get;
+
+pub(super) const PATH: &str = "/combined_credits";
+
+pub(super) fn router() -> Router {
+    Router::new().route("/", get(get::combined_credits))
+}
+
+mod get {
+    use crate::api::ApiState;
+    use crate::api::feed::messages::Messages;
+    use crate::api::feed::person_ids::PersonIds;
+    use crate::api::feed::query_args::QueryArgs;
+    use crate::api::feed::{
+        FeedMetadata, fetch_people_credits, image_url_builder, merge_credits, record_history,
+        regional_release_dates, render, select_items,
+    };
+    use axum::Extension;
+    use axum::extract::Query;
+    use axum::http::HeaderMap;
+    use axum::response::Response;
+    use itertools::Itertools;
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    #[derive(Deserialize, Debug)]
+    pub(super) struct PeopleQueryArgs {
+        /// Comma separated list of TMDB person ids
+        ids: PersonIds,
+    }
+
+    pub(super) async fn combined_credits(
+        api_state: Extension<Arc<ApiState>>,
+        people_query: Query<PeopleQueryArgs>,
+        query: Query<QueryArgs>,
+        headers: HeaderMap,
+    ) -> Response {
+        let localisation = query.localisation();
+
+        let (people_credits, images) = tokio::join!(
+            fetch_people_credits(&api_state.tmdb, &people_query.ids, &localisation),
+            image_url_builder(&api_state.tmdb),
+        );
+
+        let people_credits = match people_credits {
+            Ok(people_credits) => people_credits,
+            Err(response) => return response,
+        };
+
+        let (people, mut items) = merge_credits(people_credits);
+        record_history(&api_state.store, &mut items, query.lang.as_ref()).await;
+        regional_release_dates(&api_state.tmdb, &mut items, &query).await;
+
+        let format = query.feed_format(&headers);
+        let items = select_items(items, &query, format);
+
+        let id = format!(
+            "urn:movie-feed:people:{}",
+            people_query.ids.iter().join(",")
+        );
+
+        render(
+            format,
+            FeedMetadata::people(id, None, people, Messages::new(query.lang.as_ref())),
+            items,
+            &images,
+            &headers,
+            &api_state.validators,
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::api::feed::feed_format::FeedFormat;
+        use crate::api::feed::release_status::ReleaseStatus;
+        use crate::store::Store;
+        use axum::body::HttpBody;
+        use axum::http::StatusCode;
+        use std::collections::HashMap;
+        use tmdb::Tmdb;
+        use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+        use tmdb_test_utils::api::v3::person::combined_credits::mock_get_person_combined_credits;
+        use tmdb_test_utils::api::v3::person::mock_get_person_details;
+        use tmdb_test_utils::mockito::{Mock, ServerGuard};
+        use tmdb_test_utils::start_mock_tmdb_api;
+
+        async fn init(person_ids: &[i32]) -> (Tmdb, ServerGuard, Vec<Mock>) {
+            let mut server = start_mock_tmdb_api().await;
+            let mut mocks = Vec::with_capacity(person_ids.len() * 2 + 1);
+
+            for person_id in person_ids {
+                mocks.push(mock_get_person_details(&mut server, *person_id).await);
+                mocks.push(
+                    mock_get_person_combined_credits(&mut server, person_id.to_string().as_str())
+                        .await,
+                );
+            }
+
+            mocks.push(mock_get_configuration(&mut server).await);
+
+            let mut tmdb = Tmdb::default();
+            tmdb.override_api_url(server.url().as_str()).unwrap();
+
+            (tmdb, server, mocks)
+        }
+
+        async fn combined_credits(
+            person_ids: &[i32],
+            query_args: QueryArgs,
+        ) -> (StatusCode, axum::body::Bytes) {
+            let (tmdb, _server, _) = init(person_ids).await;
+
+            let api_state = ApiState::new(tmdb, HashMap::new(), Store::in_memory().unwrap());
+            let people_query = PeopleQueryArgs {
+                ids: PersonIds::try_from(person_ids.to_vec()).unwrap(),
+            };
+
+            let response = super::combined_credits(
+                Extension(Arc::new(api_state)),
+                Query(people_query),
+                Query(query_args),
+                HeaderMap::new(),
+            )
+            .await;
+
+            let status = response.status();
+            let body = response.into_body();
+
+            let size = {
+                let size = body.size_hint();
+                usize::try_from(size.exact().unwrap_or(size.lower()))
+            }
+            .unwrap();
+
+            (status, axum::body::to_bytes(body, size).await.unwrap())
+        }
+
+        #[tokio::test]
+        async fn test_get_default() {
+            let query_args = QueryArgs::default();
+            let (status, bytes) = combined_credits(&[19498, 956], query_args).await;
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/people/combined_credits/get/default.xml"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_json_release_status_all() {
+            let query_args = QueryArgs {
+                release_status: ReleaseStatus::All,
+                format: Some(FeedFormat::Json),
+                ..QueryArgs::default()
+            };
+            let (status, bytes) = combined_credits(&[19498, 956], query_args).await;
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/people/combined_credits/get/json/release_status/all.json"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_single_person_matches_items() {
+            let query_args = QueryArgs {
+                release_status: ReleaseStatus::All,
+                ..QueryArgs::default()
+            };
+            let (_, people_bytes) = combined_credits(&[19498], query_args).await;
+
+            let people = String::from_utf8_lossy(people_bytes.as_ref());
+            assert_eq!(
+                people.matches("<item>").count(),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/release_status/all.xml"
+                )
+                .matches("<item>")
+                .count()
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_missing_person() {
+            let (tmdb, _server, _) = init(&[19498]).await;
+
+            let response = super::combined_credits(
+                Extension(Arc::new(ApiState::new(
+                    tmdb,
+                    HashMap::new(),
+                    Store::in_memory().unwrap(),
+                ))),
+                Query(PeopleQueryArgs {
+                    ids: PersonIds::try_from("19498,1").unwrap(),
+                }),
+                Query(QueryArgs::default()),
+                HeaderMap::new(),
+            )
+            .await;
+
+            assert_ne!(response.status(), StatusCode::OK);
+        }
+    }
+}
@@ -1,10 +1,4 @@
-mod query_args;
-mod release_status;
-mod size;
-mod sort_order;
-
 use axum::Router;
-use axum::http::StatusCode;
 use axum::routing::get;
 
 pub(super) const PATH: &str = "/{person_id}/combined_credits";
@@ -14,257 +8,107 @@ pub(super) fn router() -> Router {
 }
 
 mod get {
-    use super::*;
     use crate::api::ApiState;
-    use crate::api::process_result::{ProcessedResponse, process_response};
-    use crate::api::routes::person::combined_credits::query_args::QueryArgs;
-    use crate::api::rss::Rss;
-    use ammonia::Builder;
+    use crate::api::feed::person_feed;
+    use crate::api::feed::query_args::QueryArgs;
     use axum::Extension;
     use axum::extract::{Path, Query};
-    use axum::response::{IntoResponse, Response};
-    use chrono::Utc;
-    #[cfg(test)]
-    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
-    use itertools::Itertools;
-    use rss::{Category, ChannelBuilder, Guid, GuidBuilder, Item, ItemBuilder};
-    use std::collections::HashSet;
-    use std::hash::{DefaultHasher, Hash, Hasher};
+    use axum::http::HeaderMap;
+    use axum::response::Response;
     use std::sync::Arc;
-    use std::time::Duration;
-    use tmdb::endpoints::v3::person::combined_credits::get as get_combined_credits;
-    use tmdb::endpoints::v3::person::get as get_person_details;
-    use tmdb::models::v3::credit::{Credit, IsCredit};
-    use tracing::warn;
-
-    const TTL: Duration = Duration::from_secs(60 * 60); // 60 minutes
-
-    #[cfg(test)]
-    const TEST_BUILD_DATE: NaiveDate = NaiveDate::from_ymd_opt(2025, 5, 21).unwrap();
-    #[cfg(test)]
-    const TEST_BUILD_TIME: NaiveTime = NaiveTime::from_hms_opt(18, 20, 44).unwrap();
-
-    /// A hash of the following fields of a Credit
-    /// - ID
-    /// - Title
-    /// - Release Date
-    /// - Media Type
-    /// - Credit Type
-    fn credit_guid(credit: &impl IsCredit) -> Guid {
-        let mut hasher = DefaultHasher::default();
-
-        credit.id().hash(&mut hasher);
-        credit.title().hash(&mut hasher);
-        credit.release_date().hash(&mut hasher);
-        credit.media_type().hash(&mut hasher);
-        credit.credit_type().hash(&mut hasher);
-
-        GuidBuilder::default()
-            .value(hasher.finish().to_string())
-            .permalink(false)
-            .build()
-    }
-
-    #[inline]
-    fn sanitise_text<S: AsRef<str>>(text: S) -> String {
-        let mut s = text.as_ref().replace('\n', "<br>");
-
-        let mut tags = HashSet::new();
-        tags.insert("br");
-        tags.insert("p");
-
-        s = Builder::new().tags(tags).clean(s.as_ref()).to_string();
-
-        s
-    }
 
     pub(super) async fn combined_credits(
         Path(person_id): Path<i32>,
         api_state: Extension<Arc<ApiState>>,
         query: Query<QueryArgs>,
+        headers: HeaderMap,
     ) -> Response {
-        let details = match process_response(get_person_details(&api_state.tmdb, person_id).await) {
-            ProcessedResponse::Ok(details) => details,
-            ProcessedResponse::Err(error) => {
-                warn!("{error}");
-                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
-            }
-            ProcessedResponse::Response(response) => return response,
-        };
-
-        let credits = match process_response(
-            get_combined_credits(&api_state.tmdb, &person_id.to_string()).await,
-        ) {
-            ProcessedResponse::Ok(credits) => credits,
-            ProcessedResponse::Err(error) => {
-                warn!("{error}");
-
-                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
-            }
-            ProcessedResponse::Response(response) => return response,
-        };
-
-        let cast_iter = credits.cast.into_iter().map(Credit::Cast);
-        let crew_iter = credits.crew.into_iter().map(Credit::Crew);
-
-        let mut credits = cast_iter
-            .merge_by(crew_iter, |_a, _b| true)
-            .filter(|credit| query.release_status.check(credit.release_date()))
-            .collect_vec();
-
-        credits.sort_by(|a, b| {
-            query
-                .sort_order
-                .sort_release_date(a.release_date(), b.release_date())
-        });
-
-        let mut items: Vec<Item> = Vec::with_capacity(query.size.get());
-
-        for credit in credits.into_iter().take(query.size.get()) {
-            let mut item = ItemBuilder::default();
-
-            item.guid(Some(credit_guid(&credit)));
-            item.category(Category::from(sanitise_text(
-                credit.media_type().to_string(),
-            )));
-
-            let mut description = credit
-                .overview_len()
-                .map(String::with_capacity)
-                .unwrap_or(String::new());
-
-            description.push_str("<p>");
-
-            match &credit {
-                Credit::Cast(cast) => {
-                    description.push_str("Character: ");
-
-                    match cast.character() {
-                        None => {
-                            description.push_str("TBA");
-                        }
-                        Some(character) => {
-                            description.push_str(character.as_str());
-                            item.category(Category::from(sanitise_text(character)));
-                        }
-                    }
-                }
-                Credit::Crew(crew) => {
-                    description.push_str("Department: ");
-                    description.push_str(crew.department());
-
-                    description.push_str("<br>Job: ");
-                    description.push_str(crew.job());
-                }
-            };
-
-            description.push_str("<br>Genres: ");
-            credit.genres().iter().enumerate().for_each(|(i, genre)| {
-                if i > 0 {
-                    description.push_str(", ");
-                }
-
-                description.push_str(genre.name());
-                item.category(Category::from(genre.name()));
-            });
-
-            description.push_str("<br>Language: ");
-            description.push_str(credit.original_language());
-
-            description.push_str("<br>Release Date: ");
-            match credit.release_date() {
-                None => description.push_str("TBA"),
-                Some(date) => {
-                    let date = date.format("%d-%b-%Y").to_string();
-                    description.push_str(date.as_str());
-                }
-            }
-
-            if let Some(overview) = credit.overview() {
-                description.push_str("</p><p>");
-                description.push_str(overview.as_str());
-                description.push_str("</p>");
-            }
-
-            item.link(credit.tmdb_media_url().to_string())
-                .title(Some(credit.title().to_string()))
-                .description(sanitise_text(description));
-
-            items.push(item.build());
-        }
-
-        #[cfg(test)]
-        let build_date: DateTime<Utc> = DateTime::from_naive_utc_and_offset(
-            NaiveDateTime::new(TEST_BUILD_DATE, TEST_BUILD_TIME),
-            Utc,
-        );
-        #[cfg(not(test))]
-        let build_date = Utc::now();
-
-        let mut channel = ChannelBuilder::default();
-
-        channel
-            .title(sanitise_text(format!(
-                "{} - Combined Credits",
-                details.name
-            )))
-            .link(details.tmdb_url())
-            .last_build_date(build_date.format("%a, %d %b %Y %H:%M %Z").to_string())
-            .generator(Some(
-                "Movie Feed <https://github.com/calum4/movie-feed/>".to_string(),
-            ))
-            .docs(Some(
-                "https://www.rssboard.org/rss-specification".to_string(),
-            ))
-            .ttl(Some((TTL.as_secs() * 60).to_string()))
-            .items(items);
-
-        if let Some(bio) = details.biography {
-            channel.description(sanitise_text(bio));
-        }
-
-        let rss = Rss::new(channel.build());
-        rss.into_response()
+        person_feed(&api_state, person_id, &query, &headers).await
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
-        use crate::api::routes::person::combined_credits::release_status::ReleaseStatus;
-        use crate::api::routes::person::combined_credits::size::Size;
+        use crate::api::feed::feed_format::FeedFormat;
+        use crate::api::feed::release_status::ReleaseStatus;
+        use crate::api::feed::size::Size;
+        use crate::store::Store;
         use axum::body::HttpBody;
+        use axum::http::HeaderValue;
+        use axum::http::StatusCode;
+        use axum::http::header::{
+            ACCEPT, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, VARY,
+        };
         use chrono::TimeDelta;
+        use std::collections::HashMap;
+        use std::str::FromStr;
         use tmdb::Tmdb;
-        use tmdb_test_utils::api::v3::person::combined_credits::mock_get_person_combined_credits;
-        use tmdb_test_utils::api::v3::person::mock_get_person_details;
+        use tmdb::models::v3::language::Language;
+        use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+        use tmdb_test_utils::api::v3::person::combined_credits::{
+            mock_get_person_combined_credits, mock_get_person_combined_credits_with_language,
+        };
+        use tmdb_test_utils::api::v3::person::external_ids::mock_get_person_external_ids;
+        use tmdb_test_utils::api::v3::person::{
+            mock_get_person_details, mock_get_person_details_with_language,
+        };
         use tmdb_test_utils::mockito::{Mock, ServerGuard};
         use tmdb_test_utils::start_mock_tmdb_api;
 
-        async fn init(person_id: i32) -> (Tmdb, ServerGuard, (Mock, Mock)) {
+        async fn init(person_id: i32) -> (Tmdb, ServerGuard, (Mock, Mock, Mock, Mock)) {
             let mut server = start_mock_tmdb_api().await;
 
             let details_mock = mock_get_person_details(&mut server, person_id).await;
             let credits_mock =
                 mock_get_person_combined_credits(&mut server, person_id.to_string().as_str()).await;
+            let external_ids_mock = mock_get_person_external_ids(&mut server, person_id).await;
+            let configuration_mock = mock_get_configuration(&mut server).await;
 
             let mut tmdb = Tmdb::default();
             tmdb.override_api_url(server.url().as_str()).unwrap();
 
-            (tmdb, server, (details_mock, credits_mock))
+            (
+                tmdb,
+                server,
+                (
+                    details_mock,
+                    credits_mock,
+                    external_ids_mock,
+                    configuration_mock,
+                ),
+            )
         }
 
         async fn combined_credits(person_id: i32, query_args: QueryArgs) -> axum::body::Bytes {
+            combined_credits_with_headers(person_id, query_args, HeaderMap::new()).await
+        }
+
+        async fn combined_credits_response(
+            person_id: i32,
+            query_args: QueryArgs,
+            headers: HeaderMap,
+        ) -> Response {
             let (tmdb, _server, _) = init(person_id).await;
 
-            let api_state = ApiState { tmdb };
+            let api_state = ApiState::new(tmdb, HashMap::new(), Store::in_memory().unwrap());
 
-            let body = super::combined_credits(
+            super::combined_credits(
                 Path(person_id),
                 Extension(Arc::new(api_state)),
                 Query(query_args),
+                headers,
             )
             .await
-            .into_body();
+        }
+
+        async fn combined_credits_with_headers(
+            person_id: i32,
+            query_args: QueryArgs,
+            headers: HeaderMap,
+        ) -> axum::body::Bytes {
+            let body = combined_credits_response(person_id, query_args, headers)
+                .await
+                .into_body();
 
             let size = {
                 let size = body.size_hint();
@@ -350,6 +194,251 @@ mod get {
             );
         }
 
+        #[tokio::test]
+        async fn test_get_no_external_ids() {
+            const PERSON_ID: i32 = 48000;
+
+            let query_args = QueryArgs {
+                release_status: ReleaseStatus::All,
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            let feed = String::from_utf8_lossy(bytes.as_ref());
+            assert!(feed.contains("<title>Hazy Osterwald - Combined Credits</title>"));
+            assert!(!feed.contains("<atom:link"));
+
+            let query_args = QueryArgs {
+                format: Some(FeedFormat::Atom),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            let feed = String::from_utf8_lossy(bytes.as_ref());
+            assert!(!feed.contains(r#"rel="related""#));
+        }
+
+        #[tokio::test]
+        async fn test_get_localised() {
+            const PERSON_ID: i32 = 19498;
+
+            let mut server = start_mock_tmdb_api().await;
+            let details_mock =
+                mock_get_person_details_with_language(&mut server, PERSON_ID, "de-DE").await;
+            let credits_mock = mock_get_person_combined_credits_with_language(
+                &mut server,
+                PERSON_ID.to_string().as_str(),
+                "de-DE",
+            )
+            .await;
+            let _external_ids_mock = mock_get_person_external_ids(&mut server, PERSON_ID).await;
+            let _configuration_mock = mock_get_configuration(&mut server).await;
+
+            let mut tmdb = Tmdb::default();
+            tmdb.override_api_url(server.url().as_str()).unwrap();
+
+            let api_state = ApiState::new(tmdb, HashMap::new(), Store::in_memory().unwrap());
+
+            let response = super::combined_credits(
+                Path(PERSON_ID),
+                Extension(Arc::new(api_state)),
+                Query(QueryArgs {
+                    lang: Some(Language::from_str("de-DE").unwrap()),
+                    ..QueryArgs::default()
+                }),
+                HeaderMap::new(),
+            )
+            .await;
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/locale/de.xml"
+                )
+            );
+
+            details_mock.assert();
+            credits_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_localised_fr() {
+            const PERSON_ID: i32 = 19498;
+            const LANGUAGE: &str = "fr-FR";
+
+            let mut server = start_mock_tmdb_api().await;
+            let _details_mock =
+                mock_get_person_details_with_language(&mut server, PERSON_ID, LANGUAGE).await;
+            let _credits_mock = mock_get_person_combined_credits_with_language(
+                &mut server,
+                PERSON_ID.to_string().as_str(),
+                LANGUAGE,
+            )
+            .await;
+            let _external_ids_mock = mock_get_person_external_ids(&mut server, PERSON_ID).await;
+            let _configuration_mock = mock_get_configuration(&mut server).await;
+
+            let mut tmdb = Tmdb::default();
+            tmdb.override_api_url(server.url().as_str()).unwrap();
+
+            let api_state = ApiState::new(tmdb, HashMap::new(), Store::in_memory().unwrap());
+
+            let response = super::combined_credits(
+                Path(PERSON_ID),
+                Extension(Arc::new(api_state)),
+                Query(QueryArgs {
+                    lang: Some(Language::from_str(LANGUAGE).unwrap()),
+                    ..QueryArgs::default()
+                }),
+                HeaderMap::new(),
+            )
+            .await;
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/locale/fr.xml"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_atom_default() {
+            const PERSON_ID: i32 = 19498;
+
+            let query_args = QueryArgs {
+                format: Some(FeedFormat::Atom),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/atom/default.xml"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_atom_accept_header() {
+            const PERSON_ID: i32 = 19498;
+
+            let mut headers = HeaderMap::new();
+            headers.insert(ACCEPT, HeaderValue::from_static("application/atom+xml"));
+
+            let bytes =
+                combined_credits_with_headers(PERSON_ID, QueryArgs::default(), headers).await;
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/atom/default.xml"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_format_overrides_accept_header() {
+            const PERSON_ID: i32 = 19498;
+
+            let mut headers = HeaderMap::new();
+            headers.insert(ACCEPT, HeaderValue::from_static("application/atom+xml"));
+
+            let query_args = QueryArgs {
+                format: Some(FeedFormat::Rss),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits_with_headers(PERSON_ID, query_args, headers).await;
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/default.xml"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_json_default() {
+            const PERSON_ID: i32 = 19498;
+
+            let query_args = QueryArgs {
+                format: Some(FeedFormat::Json),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/json/default.json"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_json_release_status_all() {
+            const PERSON_ID: i32 = 19498;
+
+            let query_args = QueryArgs {
+                release_status: ReleaseStatus::All,
+                format: Some(FeedFormat::Json),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/json/release_status/all.json"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_ics_release_status_all() {
+            const PERSON_ID: i32 = 19498;
+
+            let query_args = QueryArgs {
+                release_status: ReleaseStatus::All,
+                format: Some(FeedFormat::Ics),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            assert_eq!(
+                String::from_utf8_lossy(bytes.as_ref()),
+                include_str!(
+                    "../../../../tests/assets/api/person/combined_credits/get/ics/release_status/all.ics"
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_ics_no_release_date() {
+            const PERSON_ID: i32 = 19498;
+
+            let query_args = QueryArgs {
+                release_status: ReleaseStatus::NoReleaseDate,
+                format: Some(FeedFormat::Ics),
+                ..QueryArgs::default()
+            };
+            let bytes = combined_credits(PERSON_ID, query_args).await;
+
+            let calendar = String::from_utf8_lossy(bytes.as_ref());
+            assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+            assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 0);
+        }
+
         #[tokio::test]
         async fn test_size_default() {
             const PERSON_ID: i32 = 19498;
@@ -388,5 +477,65 @@ mod get {
             let credits = String::from_utf8_lossy(bytes.as_ref());
             assert_eq!(credits.matches("<item>").count(), 30);
         }
+
+        #[tokio::test]
+        async fn test_get_etag() {
+            const PERSON_ID: i32 = 19498;
+
+            let response =
+                combined_credits_response(PERSON_ID, QueryArgs::default(), HeaderMap::new()).await;
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                response.headers().get(LAST_MODIFIED).unwrap(),
+                "Wed, 21 May 2025 18:20:44 GMT"
+            );
+            assert_eq!(response.headers().get(VARY).unwrap(), "accept");
+
+            let etag = response.headers().get(ETAG).unwrap().clone();
+            // stable across builds, such that the caches of clients remain valid
+            assert_eq!(etag, "\"badeb5322cef7ee8\"");
+
+            let mut headers = HeaderMap::new();
+            headers.insert(IF_NONE_MATCH, etag.clone());
+
+            let response =
+                combined_credits_response(PERSON_ID, QueryArgs::default(), headers).await;
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+            assert_eq!(response.headers().get(ETAG), Some(&etag));
+            assert_eq!(response.headers().get(VARY).unwrap(), "accept");
+
+            let query_args = QueryArgs {
+                format: Some(FeedFormat::Atom),
+                ..QueryArgs::default()
+            };
+            let response = combined_credits_response(PERSON_ID, query_args, HeaderMap::new()).await;
+            assert_ne!(response.headers().get(ETAG), Some(&etag));
+        }
+
+        #[tokio::test]
+        async fn test_get_if_modified_since() {
+            const PERSON_ID: i32 = 19498;
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                IF_MODIFIED_SINCE,
+                HeaderValue::from_static("Wed, 21 May 2025 18:20:44 GMT"),
+            );
+
+            let response =
+                combined_credits_response(PERSON_ID, QueryArgs::default(), headers).await;
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                IF_MODIFIED_SINCE,
+                HeaderValue::from_static("Tue, 20 May 2025 18:20:44 GMT"),
+            );
+
+            let response =
+                combined_credits_response(PERSON_ID, QueryArgs::default(), headers).await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
     }
 }
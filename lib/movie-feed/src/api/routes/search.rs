@@ -0,0 +1,9 @@
+use axum::Router;
+
+mod person;
+
+pub(super) const PATH: &str = "/search";
+
+pub(super) fn router() -> Router {
+    Router::new().nest(person::PATH, person::router())
+}
@@ -0,0 +1,217 @@
+use axum::Router;
+use axum::routing::get;
+
+pub(super) const PATH: &str = "/person";
+
+pub(super) fn router() -> Router {
+    Router::new().route("/", get(get::search_person))
+}
+
+mod get {
+    use crate::api::ApiState;
+    use crate::api::feed::image_url_builder;
+    use crate::api::process_result::{ProcessedResponse, process_response};
+    use axum::extract::Query;
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::{Extension, Json};
+    use itertools::Itertools;
+    use serde::{Deserialize, Serialize};
+    use std::num::NonZeroU32;
+    use std::sync::Arc;
+    use tmdb::endpoints::v3::search::person::get as get_search_person;
+    use tmdb::image_url::ImageUrlBuilder;
+    use tmdb::models::v3::person_search::PersonSearchResult;
+    use tracing::warn;
+    use url::Url;
+
+    /// The size of the profile images linked within the results
+    const PROFILE_SIZE: &str = "w185";
+
+    #[derive(Deserialize, Debug)]
+    pub(super) struct SearchQueryArgs {
+        /// The name to search for
+        query: String,
+        /// The page of results, starting from 1
+        #[serde(default = "first_page")]
+        page: NonZeroU32,
+    }
+
+    fn first_page() -> NonZeroU32 {
+        NonZeroU32::MIN
+    }
+
+    #[derive(Serialize, Debug)]
+    pub(super) struct SearchResponse {
+        page: u32,
+        total_pages: u32,
+        total_results: u32,
+        results: Vec<SearchResult>,
+    }
+
+    #[derive(Serialize, Debug)]
+    pub(super) struct SearchResult {
+        id: i32,
+        name: String,
+        known_for_department: Option<String>,
+        profile_url: Option<String>,
+        tmdb_url: String,
+        /// The combined credits feed of the person
+        feed_url: String,
+    }
+
+    impl SearchResult {
+        fn new(result: PersonSearchResult, images: &ImageUrlBuilder, base_url: &str) -> Self {
+            Self {
+                id: result.id,
+                profile_url: result.profile_url(images, PROFILE_SIZE).map(String::from),
+                tmdb_url: result.tmdb_url().to_string(),
+                feed_url: format!("{base_url}/person/{}/combined_credits", result.id),
+                name: result.name,
+                known_for_department: result.known_for_department,
+            }
+        }
+    }
+
+    /// The configured public url of the api, used for building feed urls. Empty, and therefore
+    /// producing relative urls, should it not be configured.
+    fn base_url(public_url: Option<&Url>) -> &str {
+        public_url.map_or("", |url| url.as_str().trim_end_matches('/'))
+    }
+
+    pub(super) async fn search_person(
+        api_state: Extension<Arc<ApiState>>,
+        query: Query<SearchQueryArgs>,
+    ) -> Response {
+        let search_query = query.query.trim();
+
+        if search_query.is_empty() {
+            return (StatusCode::BAD_REQUEST, "query must not be empty").into_response();
+        }
+
+        let (response, images) = tokio::join!(
+            get_search_person(&api_state.tmdb, search_query, query.page.get()),
+            image_url_builder(&api_state.tmdb),
+        );
+
+        let response = match process_response(response) {
+            ProcessedResponse::Ok(response) => response,
+            ProcessedResponse::Err(error) => {
+                warn!("{error}");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+            ProcessedResponse::Response(response) => return response,
+        };
+
+        let base_url = base_url(api_state.public_url.as_ref());
+
+        Json(SearchResponse {
+            page: response.page,
+            total_pages: response.total_pages,
+            total_results: response.total_results,
+            results: response
+                .results
+                .into_iter()
+                .map(|result| SearchResult::new(result, &images, base_url))
+                .collect_vec(),
+        })
+        .into_response()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::store::Store;
+        use serde_json::{Value, json};
+        use std::collections::HashMap;
+        use tmdb::Tmdb;
+        use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+        use tmdb_test_utils::api::v3::search::person::mock_get_search_person;
+        use tmdb_test_utils::start_mock_tmdb_api;
+
+        const QUERY: &str = "bernthal";
+
+        async fn search_person_response(query: &str, public_url: Option<&str>) -> Response {
+            let mut server = start_mock_tmdb_api().await;
+            let _mock = mock_get_search_person(&mut server, QUERY, 1).await;
+            let _configuration_mock = mock_get_configuration(&mut server).await;
+
+            let mut tmdb = Tmdb::default();
+            tmdb.override_api_url(server.url().as_str()).unwrap();
+
+            let api_state = ApiState::new(tmdb, HashMap::new(), Store::in_memory().unwrap())
+                .with_public_url(public_url.map(|url| Url::parse(url).unwrap()));
+
+            super::search_person(
+                Extension(Arc::new(api_state)),
+                Query(SearchQueryArgs {
+                    query: query.to_string(),
+                    page: first_page(),
+                }),
+            )
+            .await
+        }
+
+        async fn search_person(public_url: Option<&str>) -> Value {
+            let response = search_person_response(QUERY, public_url).await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+
+            serde_json::from_slice(bytes.as_ref()).unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_search_person() {
+            assert_eq!(
+                search_person(Some("http://localhost:8080")).await,
+                json!({
+                    "page": 1,
+                    "total_pages": 1,
+                    "total_results": 2,
+                    "results": [
+                        {
+                            "id": 19498,
+                            "name": "Jon Bernthal",
+                            "known_for_department": "Acting",
+                            "profile_url": "https://image.tmdb.org/t/p/w185/o0t6EVkJOrFAjESDilZUlf46IbQ.jpg",
+                            "tmdb_url": "https://www.themoviedb.org/person/19498",
+                            "feed_url": "http://localhost:8080/person/19498/combined_credits",
+                        },
+                        {
+                            "id": 1772255,
+                            "name": "Kate Bernthal",
+                            "known_for_department": "Production",
+                            "profile_url": null,
+                            "tmdb_url": "https://www.themoviedb.org/person/1772255",
+                            "feed_url": "http://localhost:8080/person/1772255/combined_credits",
+                        },
+                    ],
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_search_person_feed_url() {
+            let value = search_person(None).await;
+            assert_eq!(
+                value["results"][0]["feed_url"],
+                "/person/19498/combined_credits"
+            );
+
+            let value = search_person(Some("https://feeds.example.com/movie-feed/")).await;
+            assert_eq!(
+                value["results"][0]["feed_url"],
+                "https://feeds.example.com/movie-feed/person/19498/combined_credits"
+            );
+        }
+
+        #[tokio::test]
+        async fn test_search_person_empty_query() {
+            let response = search_person_response("  ", None).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+}
@@ -1,50 +1,203 @@
+use crate::api::REQUEST_TIMEOUT;
+use crate::api::feed::person_ids::{PersonIds, PersonIdsError};
+use crate::api::feed::query_args::QueryArgs;
 use axum_client_ip::ClientIpSource;
+use clap::Parser;
 use figment::Figment;
-use figment::providers::{Env, Serialized};
+use figment::providers::{Env, Format, Serialized, Toml};
+use itertools::Itertools;
 use secrecy::SecretString;
-use serde::{Deserialize, Serialize};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 use std::fs::read_to_string;
 use std::net::{IpAddr, Ipv4Addr};
+use std::num::{NonZeroU32, NonZeroU64, NonZeroUsize};
+use std::path::PathBuf;
 use std::sync::OnceLock;
+use std::time::Duration;
+use thiserror::Error;
+use tmdb::Tmdb;
+use tmdb::builder::TmdbBuilder;
+use tmdb::cache::CacheTtl;
+use tmdb::cache::Endpoint;
+use tmdb::cache::disk::DiskCache;
+use tmdb::cache::memory::MemoryCache;
+use tmdb::localisation::Localisation;
+use tmdb::models::v3::language::Language;
+use tmdb::models::v3::region::Region;
+use tmdb::retry::RetryPolicy;
+use tmdb::throttle::ThrottleConfig;
+use url::Url;
+
+const ENV_PREFIX: &str = "MOVIE_FEED.";
+const USER_AGENT: &str = concat!("movie-feed/", env!("CARGO_PKG_VERSION"));
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct Config {
     pub(crate) tmdb_token: SecretString,
     #[serde(default)]
     pub(crate) api: ApiConfig,
+    #[serde(default)]
+    pub(crate) tmdb: TmdbConfig,
+    /// Named watchlists, each served at `/lists/{name}`
+    #[serde(default)]
+    pub(crate) lists: HashMap<String, WatchlistConfig>,
+    /// Path of the SQLite database recording the history of credits, held in memory if not
+    /// provided
+    pub(crate) store_path: Option<PathBuf>,
+}
+
+/// Command line arguments, these take precedence over all other config sources
+#[derive(Debug, Default, Parser)]
+#[command(version, about)]
+pub(crate) struct Args {
+    /// Path to a TOML config file
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Address on which to listen for API requests
+    #[arg(long)]
+    listen_address: Option<IpAddr>,
+    /// Port on which to listen for API requests
+    #[arg(long)]
+    listen_port: Option<u16>,
+    /// Path to a file containing the TMDB API read access token
+    #[arg(long)]
+    tmdb_token_file: Option<PathBuf>,
+    /// Path of the SQLite database recording the history of credits
+    #[arg(long)]
+    store_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum ConfigError {
+    #[error(transparent)]
+    Figment(#[from] Box<figment::Error>),
+    #[error("unable to read the tmdb token file at {}: {source}", path.display())]
+    TmdbTokenFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("missing tmdb token, either tmdb_token or tmdb_token_file must be provided")]
+    MissingTmdbToken,
+    #[error("list {name} is misconfigured, {source}")]
+    Watchlist {
+        name: String,
+        source: WatchlistError,
+    },
 }
 
-pub(crate) fn config() -> &'static Config {
+pub(crate) fn config() -> Result<&'static Config, ConfigError> {
     static CONFIG: OnceLock<Config> = OnceLock::new();
 
-    // TODO - accept config from cli with clap
-    CONFIG.get_or_init(env_config)
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+
+    let config = load_config(&Args::parse())?;
+
+    Ok(CONFIG.get_or_init(|| config))
 }
 
 #[derive(Debug, Deserialize)]
-struct EnvConfig {
-    tmdb_token: Option<SecretString>,
-    tmdb_token_file: Option<String>,
+struct PartialConfig {
+    #[serde(flatten)]
+    tmdb_token: TmdbTokenSource,
     api: ApiConfig,
+    #[serde(default)]
+    tmdb: TmdbConfig,
+    #[serde(default)]
+    lists: HashMap<String, WatchlistConfig>,
+    store_path: Option<PathBuf>,
+}
+
+/// The tmdb token, or file containing it, provided by a config source
+#[derive(Debug, Default, Deserialize)]
+struct TmdbTokenSource {
+    tmdb_token: Option<SecretString>,
+    tmdb_token_file: Option<PathBuf>,
+}
+
+impl TmdbTokenSource {
+    fn is_empty(&self) -> bool {
+        self.tmdb_token.is_none() && self.tmdb_token_file.is_none()
+    }
+
+    /// The token, read from `tmdb_token_file` should it be provided
+    fn token(self) -> Result<Option<SecretString>, ConfigError> {
+        let Some(path) = self.tmdb_token_file else {
+            return Ok(self.tmdb_token);
+        };
+
+        let token = read_to_string(path.as_path())
+            .map_err(|source| ConfigError::TmdbTokenFile { path, source })?;
+
+        Ok(Some(token.trim().to_string().into()))
+    }
 }
 
-fn env_config() -> Config {
-    let mut config: EnvConfig = Figment::new()
-        .merge(Serialized::default("api", ApiConfig::default()))
-        .merge(Env::prefixed("MOVIE_FEED."))
+/// Constructs the config from the following sources, in order of increasing precedence:
+/// 1. Defaults
+/// 2. The TOML config file provided with `--config`
+/// 3. `MOVIE_FEED.` prefixed environment variables, e.g. `MOVIE_FEED.API.LISTEN_PORT`
+/// 4. Command line arguments
+///
+/// The tmdb token is taken from the highest precedence source providing either `tmdb_token` or
+/// `tmdb_token_file`, the latter taking precedence should a source provide both.
+fn load_config(args: &Args) -> Result<Config, ConfigError> {
+    let mut figment = Figment::new().merge(Serialized::default("api", ApiConfig::default()));
+
+    if let Some(path) = &args.config {
+        figment = figment.merge(Toml::file_exact(path));
+    }
+
+    let mut config: PartialConfig = figment
+        .merge(Env::prefixed(ENV_PREFIX))
         .extract()
-        .expect("unable to construct environment config");
+        .map_err(Box::new)?;
+    let env_tmdb_token: TmdbTokenSource = Figment::from(Env::prefixed(ENV_PREFIX))
+        .extract()
+        .map_err(Box::new)?;
 
-    if let Some(tmdb_token_file) = &config.tmdb_token_file {
-        let token = read_to_string(tmdb_token_file).expect("unable to read tmdb token file with the path provided in the MOVIE_FEED.TMDB_TOKEN_FILE environment variable");
+    if let Some(listen_address) = args.listen_address {
+        config.api.listen_address = listen_address;
+    }
 
-        config.tmdb_token = Some(token.into());
+    if let Some(listen_port) = args.listen_port {
+        config.api.listen_port = listen_port;
     }
 
-    Config {
-        tmdb_token: config.tmdb_token.expect("missing tmdb_token field"),
-        api: config.api,
+    if let Some(store_path) = &args.store_path {
+        config.store_path = Some(store_path.clone());
     }
+
+    let args_tmdb_token = TmdbTokenSource {
+        tmdb_token: None,
+        tmdb_token_file: args.tmdb_token_file.clone(),
+    };
+
+    // the merged sources only differ from the config file should the environment provide either
+    let tmdb_token = [args_tmdb_token, env_tmdb_token, config.tmdb_token]
+        .into_iter()
+        .find(|source| !source.is_empty())
+        .map(TmdbTokenSource::token)
+        .transpose()?
+        .flatten();
+
+    for (name, list) in config.lists.iter().sorted_by_key(|(name, _)| *name) {
+        list.validate().map_err(|source| ConfigError::Watchlist {
+            name: name.clone(),
+            source,
+        })?;
+    }
+
+    Ok(Config {
+        tmdb_token: tmdb_token.ok_or(ConfigError::MissingTmdbToken)?,
+        api: config.api,
+        tmdb: config.tmdb,
+        lists: config.lists,
+        store_path: config.store_path,
+    })
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -52,6 +205,15 @@ pub(crate) struct ApiConfig {
     pub(crate) listen_address: IpAddr,
     pub(crate) listen_port: u16,
     pub(crate) client_ip_source: ClientIpSource,
+    /// Users permitted to access the feeds, authentication is disabled if empty
+    #[serde(default)]
+    pub(crate) users: Vec<UserConfig>,
+    #[serde(default)]
+    pub(crate) rate_limit: RateLimitConfig,
+    /// The url through which clients reach the api, such as that of a reverse proxy, used for
+    /// linking to feeds. Links are relative if unset.
+    #[serde(default)]
+    pub(crate) public_url: Option<Url>,
 }
 
 impl Default for ApiConfig {
@@ -60,8 +222,656 @@ impl Default for ApiConfig {
             listen_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
             listen_port: 8080,
             client_ip_source: ClientIpSource::ConnectInfo,
+            users: Vec::new(),
+            rate_limit: RateLimitConfig::default(),
+            public_url: None,
         }
     }
 }
 
-// TODO - Tests
+/// Token bucket rate limit applied to each client, identified by their API token if authenticated
+/// and otherwise by their IP address
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct RateLimitConfig {
+    pub(crate) enabled: bool,
+    /// Maximum number of requests which can be made in quick succession
+    pub(crate) burst: NonZeroU32,
+    /// Number of requests replenished each minute
+    pub(crate) per_minute: NonZeroU32,
+}
+
+impl RateLimitConfig {
+    /// Sized such that a feed reader may refresh around 40 feeds at once
+    const DEFAULT_BURST: NonZeroU32 = NonZeroU32::new(40).unwrap();
+    /// Sized such that a client polling every few seconds is held to a request every 6 seconds.
+    /// Each uncached feed costs up to 2 TMDB requests per person, so even a 20 person list keeps
+    /// a client at around 7 TMDB requests a second, within the client throttle of 40 a second.
+    const DEFAULT_PER_MINUTE: NonZeroU32 = NonZeroU32::new(10).unwrap();
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            burst: Self::DEFAULT_BURST,
+            per_minute: Self::DEFAULT_PER_MINUTE,
+        }
+    }
+}
+
+/// Configuration of the TMDB client
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub(crate) struct TmdbConfig {
+    /// Language of titles and overviews should a feed not request one, e.g. `en-GB`
+    pub(crate) language: Option<Language>,
+    /// Region whose release dates are used for movies should a feed not request one, e.g. `GB`
+    pub(crate) region: Option<Region>,
+    /// Seconds allowed for each request to TMDB
+    pub(crate) timeout_secs: NonZeroU64,
+    /// Seconds allowed to connect to TMDB
+    pub(crate) connect_timeout_secs: NonZeroU64,
+    /// Proxy through which requests to TMDB are made, e.g. `http://127.0.0.1:3128`
+    pub(crate) proxy: Option<String>,
+    /// Maximum number of requests to TMDB in flight at once
+    pub(crate) max_concurrent_requests: NonZeroUsize,
+    /// Maximum number of requests to TMDB started per second
+    pub(crate) requests_per_second: NonZeroU32,
+    /// Number of times a request which is rate limited, or otherwise fails with a transient
+    /// error, is retried
+    pub(crate) max_retries: u32,
+    /// Maximum number of responses cached in memory, ignored should `cache_dir` be provided
+    pub(crate) cache_capacity: NonZeroUsize,
+    /// Directory in which responses are cached such that they persist across restarts, rather
+    /// than in memory
+    pub(crate) cache_dir: Option<PathBuf>,
+    /// Seconds for which the responses of each endpoint are cached, overriding their defaults,
+    /// zero disabling caching of the endpoint
+    pub(crate) cache_ttl_secs: HashMap<Endpoint, u64>,
+}
+
+impl TmdbConfig {
+    const DEFAULT_TIMEOUT_SECS: NonZeroU64 = NonZeroU64::new(30).unwrap();
+    const DEFAULT_CONNECT_TIMEOUT_SECS: NonZeroU64 = NonZeroU64::new(10).unwrap();
+    const DEFAULT_MAX_CONCURRENT_REQUESTS: NonZeroUsize = NonZeroUsize::new(20).unwrap();
+    const DEFAULT_REQUESTS_PER_SECOND: NonZeroU32 = NonZeroU32::new(40).unwrap();
+    const DEFAULT_MAX_RETRIES: u32 = 3;
+    /// The time allowed for each request to TMDB, including retries, such that a feed request
+    /// fails with the TMDB error rather than timing out
+    const MAX_ELAPSED: Duration = Duration::from_secs(REQUEST_TIMEOUT.as_secs() * 2 / 3);
+
+    /// The builder of a TMDB client authenticated by `token` with this configuration, failing
+    /// should the `cache_dir` not be creatable
+    pub(crate) fn builder(&self, token: SecretString) -> std::io::Result<TmdbBuilder> {
+        let cache_ttl = self
+            .cache_ttl_secs
+            .iter()
+            .fold(CacheTtl::default(), |cache_ttl, (endpoint, secs)| {
+                cache_ttl.with(*endpoint, Duration::from_secs(*secs))
+            });
+
+        let mut builder = Tmdb::builder(token)
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(self.timeout_secs.get()))
+            .connect_timeout(Duration::from_secs(self.connect_timeout_secs.get()))
+            .localisation(Localisation {
+                language: self.language.clone(),
+                region: self.region.clone(),
+            })
+            .throttle(ThrottleConfig {
+                max_concurrent: Some(self.max_concurrent_requests),
+                requests_per_second: Some(self.requests_per_second),
+            })
+            .retry_policy(RetryPolicy {
+                max_retries: self.max_retries,
+                max_elapsed: Self::MAX_ELAPSED,
+                ..RetryPolicy::default()
+            })
+            .cache_ttl(cache_ttl);
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.as_str());
+        }
+
+        builder = match &self.cache_dir {
+            Some(cache_dir) => builder.cache(DiskCache::new(cache_dir)?),
+            None => builder.cache(MemoryCache::new(self.cache_capacity)),
+        };
+
+        Ok(builder)
+    }
+}
+
+impl Default for TmdbConfig {
+    fn default() -> Self {
+        Self {
+            language: None,
+            region: None,
+            timeout_secs: Self::DEFAULT_TIMEOUT_SECS,
+            connect_timeout_secs: Self::DEFAULT_CONNECT_TIMEOUT_SECS,
+            proxy: None,
+            max_concurrent_requests: Self::DEFAULT_MAX_CONCURRENT_REQUESTS,
+            requests_per_second: Self::DEFAULT_REQUESTS_PER_SECOND,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            cache_capacity: MemoryCache::DEFAULT_CAPACITY,
+            cache_dir: None,
+            cache_ttl_secs: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct UserConfig {
+    /// Username, used with HTTP basic auth
+    pub(crate) name: String,
+    /// Hex encoded SHA-256 hash of the user's API token, e.g. the output of
+    /// `printf '%s' "$TOKEN" | sha256sum`
+    pub(crate) token_sha256: TokenHash,
+}
+
+/// SHA-256 hash of an API token, (de)serialised as hex
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct TokenHash([u8; 32]);
+
+impl TokenHash {
+    pub(crate) fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for TokenHash {
+    fn from(hash: [u8; 32]) -> Self {
+        Self(hash)
+    }
+}
+
+impl Serialize for TokenHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(hex::encode(self.0).as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hash = String::deserialize(deserializer)?;
+        let mut bytes = [0; 32];
+
+        hex::decode_to_slice(hash.trim(), &mut bytes).map_err(DeError::custom)?;
+
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct WatchlistConfig {
+    /// TMDB ids of the people whose credits are included within the list
+    pub(crate) people: Vec<i32>,
+    /// Title of the feed, defaults to the names of the people
+    #[serde(default)]
+    pub(crate) title: Option<String>,
+    /// Default query arguments in the form of a query string, e.g.
+    /// `release_status=Released&size=10`. Any arguments provided with the request take precedence.
+    #[serde(default)]
+    pub(crate) query: String,
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum WatchlistError {
+    #[error("invalid people: {0}")]
+    People(#[from] PersonIdsError),
+    #[error("invalid query: {0}")]
+    Query(#[from] serde_urlencoded::de::Error),
+}
+
+impl WatchlistConfig {
+    /// Checks the people and query of the list, such that a misconfigured list is reported at
+    /// startup rather than when it is requested
+    fn validate(&self) -> Result<(), WatchlistError> {
+        PersonIds::try_from(self.people.clone())?;
+        QueryArgs::with_defaults(self.query.as_str(), None)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)] // Jail requires closures to return figment::Error
+mod tests {
+    use super::*;
+    use figment::Jail;
+    use secrecy::ExposeSecret;
+
+    #[test]
+    fn test_missing_tmdb_token() {
+        Jail::expect_with(|_jail| {
+            let error = load_config(&Args::default()).unwrap_err();
+            assert!(matches!(error, ConfigError::MissingTmdbToken));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_defaults() {
+        Jail::expect_with(|jail| {
+            jail.set_env("MOVIE_FEED.TMDB_TOKEN", "env-token");
+
+            let config = load_config(&Args::default()).unwrap();
+            let default = ApiConfig::default();
+
+            assert_eq!(config.tmdb_token.expose_secret(), "env-token");
+            assert_eq!(config.api.listen_address, default.listen_address);
+            assert_eq!(config.api.listen_port, default.listen_port);
+            assert!(config.lists.is_empty());
+            assert!(config.store_path.is_none());
+            assert!(config.api.public_url.is_none());
+            assert_eq!(config.tmdb.timeout_secs, TmdbConfig::DEFAULT_TIMEOUT_SECS);
+            assert!(config.tmdb.language.is_none());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_config_file() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [api]
+                listen_port = 9090
+
+                [lists.directors]
+                people = [956, 138]
+                query = "size=10"
+                "#,
+            )?;
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+
+            assert_eq!(config.tmdb_token.expose_secret(), "file-token");
+            assert_eq!(config.api.listen_port, 9090);
+            assert_eq!(config.lists["directors"].people, vec![956, 138]);
+            assert_eq!(config.lists["directors"].query, "size=10");
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_invalid_lists() {
+        let cases = [
+            (
+                "people = []",
+                "list directors is misconfigured, invalid people",
+            ),
+            (
+                "people = [956]\nquery = \"size=0\"",
+                "list directors is misconfigured, invalid query",
+            ),
+        ];
+
+        for (list, expected) in cases {
+            Jail::expect_with(|jail| {
+                jail.create_file(
+                    "config.toml",
+                    format!("tmdb_token = \"file-token\"\n\n[lists.directors]\n{list}\n").as_str(),
+                )?;
+
+                let args = Args {
+                    config: Some("config.toml".into()),
+                    ..Args::default()
+                };
+                let error = load_config(&args).unwrap_err();
+
+                assert!(matches!(error, ConfigError::Watchlist { .. }));
+                assert!(error.to_string().starts_with(expected), "{error}");
+
+                Ok(())
+            });
+        }
+
+        Jail::expect_with(|jail| {
+            let people = (0..=25).join(", ");
+            jail.create_file(
+                "config.toml",
+                format!("tmdb_token = \"file-token\"\n\n[lists.everyone]\npeople = [{people}]\n")
+                    .as_str(),
+            )?;
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let error = load_config(&args).unwrap_err();
+
+            assert_eq!(
+                error.to_string(),
+                "list everyone is misconfigured, invalid people: number of person ids must not exceed 25"
+            );
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_tmdb() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [tmdb]
+                language = "de-de"
+                region = "at"
+                timeout_secs = 5
+                connect_timeout_secs = 2
+                max_retries = 0
+
+                [tmdb.cache_ttl_secs]
+                person = 60
+                configuration = 0
+                "#,
+            )?;
+            jail.set_env("MOVIE_FEED.TMDB.PROXY", "http://127.0.0.1:3128");
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+
+            assert_eq!(config.tmdb.language.as_ref().unwrap().as_str(), "de-DE");
+            assert_eq!(config.tmdb.region.as_ref().unwrap().as_str(), "AT");
+            assert_eq!(config.tmdb.timeout_secs.get(), 5);
+            assert_eq!(config.tmdb.connect_timeout_secs.get(), 2);
+            assert_eq!(
+                config.tmdb.requests_per_second,
+                TmdbConfig::DEFAULT_REQUESTS_PER_SECOND
+            );
+            assert_eq!(config.tmdb.max_retries, 0);
+            assert_eq!(config.tmdb.proxy.as_deref(), Some("http://127.0.0.1:3128"));
+
+            assert_eq!(config.tmdb.cache_capacity, MemoryCache::DEFAULT_CAPACITY);
+            assert!(config.tmdb.cache_dir.is_none());
+
+            let tmdb = config
+                .tmdb
+                .builder(config.tmdb_token)
+                .unwrap()
+                .build()
+                .unwrap();
+            assert_eq!(
+                tmdb.localisation().language.as_ref().map(Language::as_str),
+                Some("de-DE")
+            );
+            assert_eq!(tmdb.retry_policy().max_retries, 0);
+            assert!(tmdb.retry_policy().max_elapsed < REQUEST_TIMEOUT);
+            assert_eq!(
+                tmdb.cache_ttl().get(Endpoint::Person),
+                Duration::from_secs(60)
+            );
+            assert_eq!(
+                tmdb.cache_ttl().get(Endpoint::Configuration),
+                Duration::ZERO
+            );
+            assert_eq!(
+                tmdb.cache_ttl().get(Endpoint::Movie),
+                Endpoint::Movie.default_ttl()
+            );
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_missing_config_file() {
+        Jail::expect_with(|_jail| {
+            let args = Args {
+                config: Some("missing.toml".into()),
+                ..Args::default()
+            };
+            let error = load_config(&args).unwrap_err();
+            assert!(matches!(error, ConfigError::Figment(_)));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_precedence() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [api]
+                listen_address = "0.0.0.0"
+                listen_port = 9090
+                "#,
+            )?;
+            jail.set_env("MOVIE_FEED.TMDB_TOKEN", "env-token");
+            jail.set_env("MOVIE_FEED.API.LISTEN_PORT", "9091");
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+
+            assert_eq!(config.tmdb_token.expose_secret(), "env-token");
+            assert_eq!(config.api.listen_address, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+            assert_eq!(config.api.listen_port, 9091);
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                listen_port: Some(9092),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+
+            assert_eq!(config.api.listen_port, 9092);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_tmdb_token_file() {
+        Jail::expect_with(|jail| {
+            jail.create_file("env_token", "env-file-token\n")?;
+            jail.create_file("cli_token", "cli-file-token\n")?;
+            jail.set_env("MOVIE_FEED.TMDB_TOKEN", "env-token");
+            jail.set_env("MOVIE_FEED.TMDB_TOKEN_FILE", "env_token");
+
+            let config = load_config(&Args::default()).unwrap();
+            assert_eq!(config.tmdb_token.expose_secret(), "env-file-token");
+
+            let args = Args {
+                tmdb_token_file: Some("cli_token".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+            assert_eq!(config.tmdb_token.expose_secret(), "cli-file-token");
+
+            let args = Args {
+                tmdb_token_file: Some("missing".into()),
+                ..Args::default()
+            };
+            let error = load_config(&args).unwrap_err();
+            assert!(matches!(error, ConfigError::TmdbTokenFile { .. }));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_tmdb_token_precedence() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token_file = "file_token"
+                "#,
+            )?;
+            jail.create_file("file_token", "file-token\n")?;
+            jail.create_file("cli_token", "cli-file-token\n")?;
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+            assert_eq!(config.tmdb_token.expose_secret(), "file-token");
+
+            jail.set_env("MOVIE_FEED.TMDB_TOKEN", "env-token");
+
+            let config = load_config(&args).unwrap();
+            assert_eq!(config.tmdb_token.expose_secret(), "env-token");
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                tmdb_token_file: Some("cli_token".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+            assert_eq!(config.tmdb_token.expose_secret(), "cli-file-token");
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_store_path() {
+        Jail::expect_with(|jail| {
+            jail.set_env("MOVIE_FEED.TMDB_TOKEN", "env-token");
+            jail.set_env("MOVIE_FEED.STORE_PATH", "env.sqlite");
+
+            let config = load_config(&Args::default()).unwrap();
+            assert_eq!(config.store_path, Some("env.sqlite".into()));
+
+            let args = Args {
+                store_path: Some("cli.sqlite".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+            assert_eq!(config.store_path, Some("cli.sqlite".into()));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_users() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [[api.users]]
+                name = "calum"
+                token_sha256 = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                "#,
+            )?;
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+
+            assert_eq!(config.api.users.len(), 1);
+            assert_eq!(config.api.users[0].name, "calum");
+            assert_eq!(
+                config.api.users[0].token_sha256.as_bytes()[..2],
+                [0x9f, 0x86]
+            );
+
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [[api.users]]
+                name = "calum"
+                token_sha256 = "not-hex"
+                "#,
+            )?;
+
+            assert!(load_config(&args).is_err());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [api.rate_limit]
+                burst = 5
+                "#,
+            )?;
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+            let default = RateLimitConfig::default();
+
+            assert!(config.api.rate_limit.enabled);
+            assert_eq!(config.api.rate_limit.burst.get(), 5);
+            assert_eq!(config.api.rate_limit.per_minute, default.per_minute);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_public_url() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "config.toml",
+                r#"
+                tmdb_token = "file-token"
+
+                [api]
+                public_url = "https://feeds.example.com/movie-feed/"
+                "#,
+            )?;
+
+            let args = Args {
+                config: Some("config.toml".into()),
+                ..Args::default()
+            };
+            let config = load_config(&args).unwrap();
+
+            assert_eq!(
+                config.api.public_url.unwrap().as_str(),
+                "https://feeds.example.com/movie-feed/"
+            );
+
+            Ok(())
+        });
+    }
+}
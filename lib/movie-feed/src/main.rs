@@ -1,10 +1,12 @@
 mod api;
 mod config;
+mod store;
 
 use crate::api::{ApiState, start_api_server};
 use crate::config::config;
-use tmdb::Tmdb;
-use tracing::error;
+use crate::store::Store;
+use std::process::ExitCode;
+use tracing::{error, warn};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::fmt::format::FmtSpan;
 
@@ -31,25 +33,66 @@ fn start_tracing() {
 }
 
 #[tokio::main]
-async fn main() {
-    let config = config();
-
+async fn main() -> ExitCode {
     start_tracing();
 
-    let http_client = reqwest::Client::new();
-    let tmdb = Tmdb::new(http_client, config.tmdb_token.clone());
-    let api_state = ApiState::new(tmdb);
+    let config = match config() {
+        Ok(config) => config,
+        Err(error) => {
+            error!("unable to load config, exiting! {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let tmdb_builder = match config.tmdb.builder(config.tmdb_token.clone()) {
+        Ok(tmdb_builder) => tmdb_builder,
+        Err(error) => {
+            error!("unable to create the tmdb cache directory, exiting! {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let tmdb = match tmdb_builder.build() {
+        Ok(tmdb) => tmdb,
+        Err(error) => {
+            error!("unable to configure the tmdb client, exiting! {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let store = match &config.store_path {
+        Some(path) => Store::open(path),
+        None => {
+            warn!(
+                "no store_path is configured, the history of credits will not persist across restarts"
+            );
+            Store::in_memory()
+        }
+    };
+
+    let store = match store {
+        Ok(store) => store,
+        Err(error) => {
+            error!("unable to open the store, exiting! {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let api_state = ApiState::new(tmdb, config.lists.clone(), store)
+        .with_public_url(config.api.public_url.clone());
 
     let handle = match start_api_server(config, api_state).await {
         Ok(handle) => handle,
         Err(error) => {
             error!("unable to start api server, exiting! {error}");
-            return;
+            return ExitCode::FAILURE;
         }
     };
 
     if let Err(error) = handle.await {
         error!("there was an issue with the api server, exiting: {error}");
-        return;
+        return ExitCode::FAILURE;
     }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,274 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rusqlite::{Connection, OptionalExtension, params};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub(crate) enum StoreError {
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
+}
+
+/// The fields of a credit which are tracked for changes
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct CreditSnapshot {
+    pub(crate) title: String,
+    pub(crate) release_date: Option<NaiveDate>,
+}
+
+/// The most recent change to the tracked fields of a credit
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct CreditChange {
+    /// The fields prior to the change
+    pub(crate) previous: CreditSnapshot,
+    /// When the change was first seen, the time at which the item was last modified within feeds
+    pub(crate) detected: DateTime<Utc>,
+}
+
+/// SQLite backed store recording when each credit, identified by its uid, was first seen and the
+/// most recent change to each credit
+pub(crate) struct Store {
+    connection: Mutex<Connection>,
+}
+
+impl Store {
+    /// Opens the store at `path`, creating it should it not exist
+    pub(crate) fn open(path: &Path) -> Result<Self, StoreError> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// A store which is discarded once dropped
+    pub(crate) fn in_memory() -> Result<Self, StoreError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(connection: Connection) -> Result<Self, StoreError> {
+        connection.execute_batch(
+            "-- keyed by guids, which change along with the title or release date of a credit
+            DROP TABLE IF EXISTS first_seen;
+
+            CREATE TABLE IF NOT EXISTS credit_first_seen (
+                uid TEXT PRIMARY KEY NOT NULL,
+                first_seen INTEGER NOT NULL
+            ) WITHOUT ROWID;
+
+            CREATE TABLE IF NOT EXISTS credit_snapshot (
+                uid TEXT PRIMARY KEY NOT NULL,
+                title TEXT NOT NULL,
+                release_date TEXT,
+                previous_title TEXT,
+                previous_release_date TEXT,
+                changed INTEGER
+            ) WITHOUT ROWID;",
+        )?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// The time at which each uid was first seen, any which have not been are recorded as `now`
+    pub(crate) fn first_seen(
+        &self,
+        uids: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<HashMap<String, DateTime<Utc>>, StoreError> {
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let transaction = connection.transaction()?;
+        let mut first_seen = HashMap::with_capacity(uids.len());
+
+        {
+            let mut insert = transaction.prepare_cached(
+                "INSERT INTO credit_first_seen (uid, first_seen) VALUES (?1, ?2) ON CONFLICT (uid) DO NOTHING",
+            )?;
+            let mut select = transaction
+                .prepare_cached("SELECT first_seen FROM credit_first_seen WHERE uid = ?1")?;
+
+            for uid in uids {
+                insert.execute(params![uid, now.timestamp()])?;
+
+                let timestamp: i64 = select.query_row([uid], |row| row.get(0))?;
+                let date = DateTime::from_timestamp(timestamp, 0).unwrap_or(now);
+
+                first_seen.insert(uid.clone(), date);
+            }
+        }
+
+        transaction.commit()?;
+
+        Ok(first_seen)
+    }
+
+    /// Compares each snapshot, identified by a uid which excludes the tracked fields, with the one
+    /// previously recorded, returning the most recent change of each credit which has changed
+    pub(crate) fn record_snapshots(
+        &self,
+        snapshots: &[(String, CreditSnapshot)],
+        now: DateTime<Utc>,
+    ) -> Result<HashMap<String, CreditChange>, StoreError> {
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let transaction = connection.transaction()?;
+        let mut changes = HashMap::new();
+
+        {
+            let mut select = transaction.prepare_cached(
+                "SELECT title, release_date, previous_title, previous_release_date, changed
+                FROM credit_snapshot WHERE uid = ?1",
+            )?;
+            let mut insert = transaction.prepare_cached(
+                "INSERT INTO credit_snapshot (uid, title, release_date) VALUES (?1, ?2, ?3)",
+            )?;
+            let mut update = transaction.prepare_cached(
+                "UPDATE credit_snapshot
+                SET title = ?2, release_date = ?3, previous_title = ?4, previous_release_date = ?5,
+                    changed = ?6
+                WHERE uid = ?1",
+            )?;
+
+            for (uid, snapshot) in snapshots {
+                let recorded = select
+                    .query_row([uid], |row| {
+                        let current = CreditSnapshot {
+                            title: row.get(0)?,
+                            release_date: row.get(1)?,
+                        };
+
+                        let previous_title: Option<String> = row.get(2)?;
+                        let previous_release_date: Option<NaiveDate> = row.get(3)?;
+                        let changed: Option<i64> = row.get(4)?;
+
+                        let change = match (previous_title, changed) {
+                            (Some(title), Some(changed)) => Some(CreditChange {
+                                previous: CreditSnapshot {
+                                    title,
+                                    release_date: previous_release_date,
+                                },
+                                detected: DateTime::from_timestamp(changed, 0).unwrap_or(now),
+                            }),
+                            _ => None,
+                        };
+
+                        Ok((current, change))
+                    })
+                    .optional()?;
+
+                let change = match recorded {
+                    None => {
+                        insert.execute(params![uid, snapshot.title, snapshot.release_date])?;
+                        None
+                    }
+                    Some((current, change)) if current == *snapshot => change,
+                    Some((current, _)) => {
+                        update.execute(params![
+                            uid,
+                            snapshot.title,
+                            snapshot.release_date,
+                            current.title,
+                            current.release_date,
+                            now.timestamp()
+                        ])?;
+
+                        Some(CreditChange {
+                            previous: current,
+                            detected: DateTime::from_timestamp(now.timestamp(), 0).unwrap_or(now),
+                        })
+                    }
+                };
+
+                if let Some(change) = change {
+                    changes.insert(uid.clone(), change);
+                }
+            }
+        }
+
+        transaction.commit()?;
+
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_first_seen_retained() {
+        let store = Store::in_memory().unwrap();
+        let now = DateTime::from_timestamp(1_747_851_644, 0).unwrap();
+        let later = now + TimeDelta::days(1);
+
+        let first_seen = store.first_seen(&["a".to_string()], now).unwrap();
+        assert_eq!(first_seen.get("a"), Some(&now));
+
+        let first_seen = store
+            .first_seen(&["a".to_string(), "b".to_string()], later)
+            .unwrap();
+        assert_eq!(first_seen.get("a"), Some(&now));
+        assert_eq!(first_seen.get("b"), Some(&later));
+    }
+
+    #[test]
+    fn test_record_snapshots() {
+        let store = Store::in_memory().unwrap();
+        let now = DateTime::from_timestamp(1_747_851_644, 0).unwrap();
+        let later = now + TimeDelta::days(1);
+
+        let snapshot = |title: &str, release_date: Option<NaiveDate>| {
+            vec![(
+                "a".to_string(),
+                CreditSnapshot {
+                    title: title.to_string(),
+                    release_date,
+                },
+            )]
+        };
+
+        let original = snapshot("Title", None);
+        assert!(store.record_snapshots(&original, now).unwrap().is_empty());
+        assert!(store.record_snapshots(&original, later).unwrap().is_empty());
+
+        let rescheduled = snapshot("Title", NaiveDate::from_ymd_opt(2025, 6, 1));
+        let expected = CreditChange {
+            previous: original[0].1.clone(),
+            detected: later,
+        };
+
+        let changes = store.record_snapshots(&rescheduled, later).unwrap();
+        assert_eq!(changes.get("a"), Some(&expected));
+
+        let changes = store
+            .record_snapshots(&rescheduled, later + TimeDelta::days(1))
+            .unwrap();
+        assert_eq!(changes.get("a"), Some(&expected));
+    }
+
+    #[test]
+    fn test_first_seen_persisted() {
+        let path = std::env::temp_dir().join(format!("movie-feed-{}.sqlite", std::process::id()));
+        let now = DateTime::from_timestamp(1_747_851_644, 0).unwrap();
+
+        let store = Store::open(&path).unwrap();
+        store.first_seen(&["a".to_string()], now).unwrap();
+        drop(store);
+
+        let store = Store::open(&path).unwrap();
+        let first_seen = store
+            .first_seen(&["a".to_string()], now + TimeDelta::days(1))
+            .unwrap();
+        assert_eq!(first_seen.get("a"), Some(&now));
+
+        drop(store);
+        std::fs::remove_file(path).unwrap();
+    }
+}
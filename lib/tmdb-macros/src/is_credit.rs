@@ -52,6 +52,16 @@ pub(crate) fn is_credit(input: proc_macro::TokenStream) -> proc_macro::TokenStre
         &ident,
         Ident::new("credit_id", Span::call_site()),
     );
+    let poster_path = propagate_trait_method(
+        &input.data,
+        &ident,
+        Ident::new("poster_path", Span::call_site()),
+    );
+    let backdrop_path = propagate_trait_method(
+        &input.data,
+        &ident,
+        Ident::new("backdrop_path", Span::call_site()),
+    );
     let media_type = propagate_trait_method(
         &input.data,
         &ident,
@@ -105,6 +115,16 @@ pub(crate) fn is_credit(input: proc_macro::TokenStream) -> proc_macro::TokenStre
                 #credit_id
             }
 
+            #[inline]
+            fn poster_path(&self) -> Option<&str> {
+                #poster_path
+            }
+
+            #[inline]
+            fn backdrop_path(&self) -> Option<&str> {
+                #backdrop_path
+            }
+
             #[inline]
             fn media_type(&self) -> crate::models::v3::media_type::MediaType {
                 #media_type
@@ -1,2 +1,7 @@
+pub mod configuration;
 pub mod errors;
+pub mod find;
+pub mod movie;
 pub mod person;
+pub mod search;
+pub mod tv;
@@ -1,5 +1,6 @@
 use crate::api::file_path;
-use http::header::CONTENT_TYPE;
+use http::Method;
+use http::header::{CONTENT_TYPE, RETRY_AFTER};
 use mockito::{Mock, ServerGuard};
 use tmdb::api_version::ApiVersion;
 
@@ -18,3 +19,29 @@ pub async fn mock_invalid_id(server: &mut ServerGuard) -> (Mock, String) {
         path,
     )
 }
+
+/// Mocks a single [rate limited](https://developer.themoviedb.org/docs/rate-limiting) response
+/// on `path`, after which any other mock on `path` is matched
+pub async fn mock_rate_limited_at_path(
+    server: &mut ServerGuard,
+    method: Method,
+    path: &str,
+    retry_after: Option<&str>,
+) -> Mock {
+    let api_version = ApiVersion::V3;
+    let response_path = format!("/{}errors/rate_limited", api_version.base_path());
+
+    let mock = server
+        .mock(method.as_str(), path)
+        .with_status(429)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(response_path.as_str(), "GET.json"));
+
+    match retry_after {
+        Some(retry_after) => mock.with_header(RETRY_AFTER, retry_after),
+        None => mock,
+    }
+    .expect(1)
+    .create_async()
+    .await
+}
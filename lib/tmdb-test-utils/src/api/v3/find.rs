@@ -0,0 +1,25 @@
+use crate::api::file_path;
+use http::header::CONTENT_TYPE;
+use mockito::{Matcher, Mock, ServerGuard};
+use tmdb::api_version::ApiVersion;
+
+pub async fn mock_get_find(
+    server: &mut ServerGuard,
+    external_id: &str,
+    external_source: &str,
+) -> Mock {
+    let api_version = ApiVersion::V3;
+    let path = format!("/{}find/{external_id}", api_version.base_path());
+
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::UrlEncoded(
+            "external_source".to_string(),
+            external_source.to_string(),
+        ))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(path.as_str(), "GET.json"))
+        .create_async()
+        .await
+}
@@ -0,0 +1,19 @@
+pub mod release_dates;
+
+use crate::api::file_path;
+use http::header::CONTENT_TYPE;
+use mockito::{Mock, ServerGuard};
+use tmdb::api_version::ApiVersion;
+
+pub async fn mock_get_movie_details(server: &mut ServerGuard, movie_id: i32) -> Mock {
+    let api_version = ApiVersion::V3;
+    let path = format!("/{}movie/{movie_id}", api_version.base_path());
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(path.as_str(), "GET.json"))
+        .create_async()
+        .await
+}
@@ -1,8 +1,9 @@
 pub mod combined_credits;
+pub mod external_ids;
 
 use crate::api::file_path;
 use http::header::CONTENT_TYPE;
-use mockito::{Mock, ServerGuard};
+use mockito::{Matcher, Mock, ServerGuard};
 use tmdb::api_version::ApiVersion;
 
 pub async fn mock_get_person_details(server: &mut ServerGuard, person_id: i32) -> Mock {
@@ -17,3 +18,26 @@ pub async fn mock_get_person_details(server: &mut ServerGuard, person_id: i32) -
         .create_async()
         .await
 }
+
+/// Mocks the person details localised in `language`, e.g. `de-DE`
+pub async fn mock_get_person_details_with_language(
+    server: &mut ServerGuard,
+    person_id: i32,
+    language: &str,
+) -> Mock {
+    let api_version = ApiVersion::V3;
+    let path = format!("/{}person/{person_id}", api_version.base_path());
+    let response_path = format!("{path}/{language}");
+
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::UrlEncoded(
+            "language".to_string(),
+            language.to_string(),
+        ))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(response_path.as_str(), "GET.json"))
+        .create_async()
+        .await
+}
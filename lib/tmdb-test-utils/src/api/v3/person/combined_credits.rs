@@ -1,6 +1,6 @@
 use crate::api::file_path;
 use http::header::CONTENT_TYPE;
-use mockito::{Mock, ServerGuard};
+use mockito::{Matcher, Mock, ServerGuard};
 use tmdb::api_version::ApiVersion;
 
 pub async fn mock_get_person_combined_credits(server: &mut ServerGuard, person_id: &str) -> Mock {
@@ -18,3 +18,29 @@ pub async fn mock_get_person_combined_credits(server: &mut ServerGuard, person_i
         .create_async()
         .await
 }
+
+/// Mocks the combined credits localised in `language`, e.g. `de-DE`
+pub async fn mock_get_person_combined_credits_with_language(
+    server: &mut ServerGuard,
+    person_id: &str,
+    language: &str,
+) -> Mock {
+    let api_version = ApiVersion::V3;
+    let path = format!(
+        "/{}person/{person_id}/combined_credits",
+        api_version.base_path()
+    );
+    let response_path = format!("{path}/{language}");
+
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::UrlEncoded(
+            "language".to_string(),
+            language.to_string(),
+        ))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(response_path.as_str(), "GET.json"))
+        .create_async()
+        .await
+}
@@ -0,0 +1,22 @@
+use crate::api::file_path;
+use http::header::CONTENT_TYPE;
+use mockito::{Matcher, Mock, ServerGuard};
+use tmdb::api_version::ApiVersion;
+
+pub async fn mock_get_search_person(server: &mut ServerGuard, query: &str, page: u32) -> Mock {
+    let api_version = ApiVersion::V3;
+    let path = format!("/{}search/person", api_version.base_path());
+    let response_path = format!("{path}/{query}/{page}");
+
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".to_string(), query.to_string()),
+            Matcher::UrlEncoded("page".to_string(), page.to_string()),
+        ]))
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(response_path.as_str(), "GET.json"))
+        .create_async()
+        .await
+}
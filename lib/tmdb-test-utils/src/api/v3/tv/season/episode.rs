@@ -0,0 +1,25 @@
+use crate::api::file_path;
+use http::header::CONTENT_TYPE;
+use mockito::{Mock, ServerGuard};
+use tmdb::api_version::ApiVersion;
+
+pub async fn mock_get_tv_episode_details(
+    server: &mut ServerGuard,
+    series_id: i32,
+    season_number: u32,
+    episode_number: u32,
+) -> Mock {
+    let api_version = ApiVersion::V3;
+    let path = format!(
+        "/{}tv/{series_id}/season/{season_number}/episode/{episode_number}",
+        api_version.base_path()
+    );
+
+    server
+        .mock("GET", path.as_str())
+        .with_status(200)
+        .with_header(CONTENT_TYPE, "application/json")
+        .with_body_from_file(file_path(path.as_str(), "GET.json"))
+        .create_async()
+        .await
+}
@@ -0,0 +1,328 @@
+use crate::cache::memory::MemoryCache;
+use crate::cache::{CacheBackend, CacheTtl, InFlight};
+use crate::localisation::Localisation;
+use crate::retry::RetryPolicy;
+use crate::throttle::{Throttle, ThrottleConfig};
+use crate::{DEFAULT_API_URL, Tmdb};
+use http::HeaderValue;
+use http::header::InvalidHeaderValue;
+use reqwest::{Client, Proxy};
+use secrecy::SecretString;
+use std::sync::Arc;
+use std::time::Duration;
+use url::{ParseError, Url};
+
+/// The user agent of requests should one not be provided
+pub const DEFAULT_USER_AGENT: &str = concat!("tmdb/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum BuildError {
+    #[error("invalid api url: {0}")]
+    ApiUrl(#[from] ParseError),
+    #[error("invalid api url {0}, must be an http or https url")]
+    ApiUrlScheme(Url),
+    #[error("invalid user agent: {0}")]
+    UserAgent(#[from] InvalidHeaderValue),
+    #[error("the {0} must be greater than zero")]
+    ZeroTimeout(&'static str),
+    #[error("the connect timeout must not exceed the timeout")]
+    ConnectTimeout,
+    #[error("the initial backoff of the retry policy must not exceed its max backoff")]
+    RetryPolicy,
+    #[error("invalid proxy: {0}")]
+    Proxy(reqwest::Error),
+    #[error("unable to build the http client: {0}")]
+    HttpClient(reqwest::Error),
+}
+
+/// Configures and validates a [`Tmdb`] client, see [`Tmdb::builder`]
+#[derive(Debug)]
+#[must_use]
+pub struct TmdbBuilder {
+    token: SecretString,
+    api_url: Option<String>,
+    timeout: Duration,
+    connect_timeout: Duration,
+    user_agent: String,
+    localisation: Localisation,
+    proxy: Option<String>,
+    retry_policy: RetryPolicy,
+    throttle: ThrottleConfig,
+    cache: Arc<dyn CacheBackend>,
+    cache_ttl: CacheTtl,
+}
+
+impl TmdbBuilder {
+    pub(crate) fn new(token: SecretString) -> Self {
+        Self {
+            token,
+            api_url: None,
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            localisation: Localisation::default(),
+            proxy: None,
+            retry_policy: RetryPolicy::default(),
+            throttle: ThrottleConfig::default(),
+            cache: Arc::new(MemoryCache::default()),
+            cache_ttl: CacheTtl::default(),
+        }
+    }
+
+    /// The base url of the api, defaulting to `https://api.themoviedb.org/`
+    pub fn api_url<U: Into<String>>(mut self, api_url: U) -> Self {
+        self.api_url = Some(api_url.into());
+        self
+    }
+
+    /// The total time allowed for each request, from connecting until the response body has been
+    /// received, defaulting to 30 seconds
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The time allowed to connect to the api, defaulting to 10 seconds
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Defaults to [`DEFAULT_USER_AGENT`]
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// See [`Tmdb::localisation`]
+    pub fn localisation(mut self, localisation: Localisation) -> Self {
+        self.localisation = localisation;
+        self
+    }
+
+    /// Proxies all requests through `proxy`, e.g. `http://127.0.0.1:3128`, instead of any
+    /// proxy configured by the environment
+    pub fn proxy<S: Into<String>>(mut self, proxy: S) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// See [`Tmdb::retry_policy`]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// See [`Tmdb::with_throttle`]
+    pub fn throttle(mut self, throttle: ThrottleConfig) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Where successful responses are cached, defaulting to a [`MemoryCache`] of
+    /// [default capacity](MemoryCache::DEFAULT_CAPACITY)
+    pub fn cache<C: CacheBackend + 'static>(mut self, cache: C) -> Self {
+        self.cache = Arc::new(cache);
+        self
+    }
+
+    /// See [`Tmdb::cache_ttl`]
+    pub fn cache_ttl(mut self, cache_ttl: CacheTtl) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    pub fn build(self) -> Result<Tmdb, BuildError> {
+        let api_url = match self.api_url {
+            None => DEFAULT_API_URL.clone(),
+            Some(api_url) => {
+                let api_url = Url::parse(api_url.as_str())?;
+
+                if !matches!(api_url.scheme(), "http" | "https") {
+                    return Err(BuildError::ApiUrlScheme(api_url));
+                }
+
+                api_url
+            }
+        };
+
+        if self.timeout.is_zero() {
+            return Err(BuildError::ZeroTimeout("timeout"));
+        }
+
+        if self.connect_timeout.is_zero() {
+            return Err(BuildError::ZeroTimeout("connect timeout"));
+        }
+
+        if self.connect_timeout > self.timeout {
+            return Err(BuildError::ConnectTimeout);
+        }
+
+        if self.retry_policy.max_elapsed.is_zero() {
+            return Err(BuildError::ZeroTimeout(
+                "max elapsed time of the retry policy",
+            ));
+        }
+
+        if self.retry_policy.initial_backoff > self.retry_policy.max_backoff {
+            return Err(BuildError::RetryPolicy);
+        }
+
+        let mut http_client = Client::builder()
+            .timeout(self.timeout)
+            .connect_timeout(self.connect_timeout)
+            .user_agent(HeaderValue::from_str(self.user_agent.as_str())?);
+
+        if let Some(proxy) = self.proxy {
+            http_client = http_client.proxy(Proxy::all(proxy).map_err(BuildError::Proxy)?);
+        }
+
+        Ok(Tmdb {
+            token: self.token,
+            http_client: http_client.build().map_err(BuildError::HttpClient)?,
+            timeout: Some(self.timeout),
+            api_url,
+            localisation: self.localisation,
+            retry_policy: self.retry_policy,
+            throttle: Throttle::new(self.throttle),
+            cache: self.cache,
+            cache_ttl: self.cache_ttl,
+            in_flight: InFlight::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::Endpoint;
+    use crate::cache::none::NoCache;
+    use crate::models::v3::language::Language;
+    use http::header::USER_AGENT;
+    use secrecy::ExposeSecret;
+    use std::str::FromStr;
+    use tmdb_test_utils::mockito::Matcher;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    fn builder() -> TmdbBuilder {
+        Tmdb::builder(SecretString::from("THIS_IS_A_TEST"))
+    }
+
+    #[test]
+    fn test_build_default() {
+        let tmdb = builder().build().unwrap();
+
+        assert_eq!(tmdb.token.expose_secret(), "THIS_IS_A_TEST");
+        assert_eq!(tmdb.api_url.as_str(), DEFAULT_API_URL.as_str());
+        assert_eq!(tmdb.localisation, Localisation::default());
+        assert_eq!(tmdb.retry_policy, RetryPolicy::default());
+        assert_eq!(tmdb.throttle(), &ThrottleConfig::default());
+        assert_eq!(tmdb.cache_ttl(), &CacheTtl::default());
+    }
+
+    #[test]
+    fn test_build() {
+        let localisation = Localisation {
+            language: Some(Language::from_str("de-DE").unwrap()),
+            region: None,
+        };
+
+        let tmdb = builder()
+            .api_url("https://example.com/")
+            .localisation(localisation.clone())
+            .retry_policy(RetryPolicy::disabled())
+            .throttle(ThrottleConfig::unlimited())
+            .proxy("http://127.0.0.1:3128")
+            .cache(NoCache)
+            .cache_ttl(CacheTtl::default().with(Endpoint::Person, Duration::ZERO))
+            .build()
+            .unwrap();
+
+        assert_eq!(tmdb.api_url.as_str(), "https://example.com/");
+        assert_eq!(tmdb.localisation, localisation);
+        assert_eq!(tmdb.retry_policy, RetryPolicy::disabled());
+        assert_eq!(tmdb.throttle(), &ThrottleConfig::unlimited());
+        assert_eq!(tmdb.cache_ttl().get(Endpoint::Person), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_build_invalid() {
+        assert!(matches!(
+            builder().api_url("INVALID_URL").build(),
+            Err(BuildError::ApiUrl(ParseError::RelativeUrlWithoutBase))
+        ));
+        assert!(matches!(
+            builder().api_url("ftp://example.com/").build(),
+            Err(BuildError::ApiUrlScheme(_))
+        ));
+        assert!(matches!(
+            builder().user_agent("movie\nfeed").build(),
+            Err(BuildError::UserAgent(_))
+        ));
+        assert!(matches!(
+            builder().timeout(Duration::ZERO).build(),
+            Err(BuildError::ZeroTimeout("timeout"))
+        ));
+        assert!(matches!(
+            builder().connect_timeout(Duration::ZERO).build(),
+            Err(BuildError::ZeroTimeout("connect timeout"))
+        ));
+        assert!(matches!(
+            builder()
+                .timeout(Duration::from_secs(5))
+                .connect_timeout(Duration::from_secs(6))
+                .build(),
+            Err(BuildError::ConnectTimeout)
+        ));
+        assert!(matches!(
+            builder()
+                .retry_policy(RetryPolicy {
+                    initial_backoff: Duration::from_secs(10),
+                    max_backoff: Duration::from_secs(1),
+                    ..RetryPolicy::default()
+                })
+                .build(),
+            Err(BuildError::RetryPolicy)
+        ));
+        assert!(matches!(
+            builder()
+                .retry_policy(RetryPolicy {
+                    max_elapsed: Duration::ZERO,
+                    ..RetryPolicy::default()
+                })
+                .build(),
+            Err(BuildError::ZeroTimeout(
+                "max elapsed time of the retry policy"
+            ))
+        ));
+        assert!(matches!(
+            builder().proxy("://").build(),
+            Err(BuildError::Proxy(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_user_agent() {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = server
+            .mock("GET", "/3/configuration")
+            .match_header(USER_AGENT.as_str(), Matcher::Exact("movie-feed/1.0".into()))
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let tmdb = builder()
+            .api_url(server.url())
+            .user_agent("movie-feed/1.0")
+            .build()
+            .unwrap();
+
+        let _ = crate::endpoints::v3::configuration::get(&tmdb).await;
+
+        mock.assert();
+    }
+}
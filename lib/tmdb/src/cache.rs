@@ -0,0 +1,249 @@
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+pub mod disk;
+pub mod memory;
+pub mod none;
+
+/// An endpoint whose responses are cached
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum Endpoint {
+    Configuration,
+    Find,
+    Movie,
+    MovieReleaseDates,
+    Person,
+    PersonCombinedCredits,
+    PersonExternalIds,
+    SearchPerson,
+    Tv,
+    TvSeason,
+    TvSeasonEpisode,
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+#[error("unknown endpoint")]
+pub struct UnknownEndpoint;
+
+impl Endpoint {
+    pub const ALL: [Endpoint; 11] = [
+        Self::Configuration,
+        Self::Find,
+        Self::Movie,
+        Self::MovieReleaseDates,
+        Self::Person,
+        Self::PersonCombinedCredits,
+        Self::PersonExternalIds,
+        Self::SearchPerson,
+        Self::Tv,
+        Self::TvSeason,
+        Self::TvSeasonEpisode,
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Configuration => "configuration",
+            Self::Find => "find",
+            Self::Movie => "movie",
+            Self::MovieReleaseDates => "movie_release_dates",
+            Self::Person => "person",
+            Self::PersonCombinedCredits => "person_combined_credits",
+            Self::PersonExternalIds => "person_external_ids",
+            Self::SearchPerson => "search_person",
+            Self::Tv => "tv",
+            Self::TvSeason => "tv_season",
+            Self::TvSeasonEpisode => "tv_season_episode",
+        }
+    }
+
+    /// How long responses are cached should [`CacheTtl`] not say otherwise
+    pub const fn default_ttl(&self) -> Duration {
+        match self {
+            Self::Configuration => Duration::from_secs(86400), // 1 day
+            _ => Duration::from_secs(3600),                    // 1 hour
+        }
+    }
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Endpoint {
+    type Err = UnknownEndpoint;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|endpoint| endpoint.as_str() == s)
+            .ok_or(UnknownEndpoint)
+    }
+}
+
+impl<'de> Deserialize<'de> for Endpoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Endpoint::from_str(s.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Identifies a cached response by its endpoint and the path and query of its request, e.g.
+/// `/3/person/19498?language=de-DE`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CacheKey {
+    endpoint: Endpoint,
+    resource: String,
+}
+
+impl CacheKey {
+    pub fn new<S: Into<String>>(endpoint: Endpoint, resource: S) -> Self {
+        Self {
+            endpoint,
+            resource: resource.into(),
+        }
+    }
+
+    pub fn endpoint(&self) -> Endpoint {
+        self.endpoint
+    }
+
+    pub fn resource(&self) -> &str {
+        self.resource.as_str()
+    }
+}
+
+impl Display for CacheKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.endpoint, self.resource)
+    }
+}
+
+/// Storage of the response bodies of successful requests, shared by all endpoints of a
+/// [`Tmdb`](crate::Tmdb) client
+pub trait CacheBackend: Debug + Send + Sync {
+    /// The value of `key`, should it be present and unexpired
+    fn get(&self, key: &CacheKey) -> Option<Vec<u8>>;
+
+    /// Inserts `value`, which expires after `ttl`, replacing any existing value of `key`
+    fn insert(&self, key: CacheKey, value: Vec<u8>, ttl: Duration);
+
+    /// Removes `key`, returning whether it was present
+    fn remove(&self, key: &CacheKey) -> bool;
+
+    /// The keys of all unexpired values
+    fn keys(&self) -> Vec<CacheKey>;
+
+    fn clear(&self);
+
+    /// Removes all values of `endpoint`
+    fn invalidate(&self, endpoint: Endpoint) {
+        self.keys()
+            .into_iter()
+            .filter(|key| key.endpoint == endpoint)
+            .for_each(|key| {
+                self.remove(&key);
+            });
+    }
+}
+
+/// How long the responses of each endpoint are cached, a zero duration disabling caching of the
+/// endpoint
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CacheTtl {
+    overrides: HashMap<Endpoint, Duration>,
+}
+
+impl CacheTtl {
+    /// Caches the responses of `endpoint` for `ttl` rather than its
+    /// [default](Endpoint::default_ttl)
+    pub fn with(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+        self.overrides.insert(endpoint, ttl);
+        self
+    }
+
+    pub fn get(&self, endpoint: Endpoint) -> Duration {
+        self.overrides
+            .get(&endpoint)
+            .copied()
+            .unwrap_or(endpoint.default_ttl())
+    }
+}
+
+/// The keys being fetched following a cache miss, such that concurrent misses of a key wait for
+/// the first to be cached rather than each requesting it
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    keys: Mutex<HashMap<CacheKey, Weak<AsyncMutex<()>>>>,
+}
+
+impl InFlight {
+    /// Waits until no other request holds `key`, holding it until the guard is dropped
+    pub(crate) async fn lock(&self, key: &CacheKey) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut keys = self.keys.lock().unwrap_or_else(|error| error.into_inner());
+
+            match keys.get(key).and_then(Weak::upgrade) {
+                Some(lock) => lock,
+                None => {
+                    keys.retain(|_, lock| lock.strong_count() > 0);
+
+                    let lock = Arc::new(AsyncMutex::new(()));
+                    keys.insert(key.clone(), Arc::downgrade(&lock));
+                    lock
+                }
+            }
+        };
+
+        lock.lock_owned().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::memory::MemoryCache;
+
+    #[test]
+    fn test_endpoint_from_str() {
+        for endpoint in Endpoint::ALL {
+            assert_eq!(Endpoint::from_str(endpoint.as_str()), Ok(endpoint));
+        }
+
+        assert_eq!(Endpoint::from_str("people"), Err(UnknownEndpoint));
+    }
+
+    #[test]
+    fn test_ttl() {
+        let ttl = CacheTtl::default().with(Endpoint::Person, Duration::from_secs(60));
+
+        assert_eq!(ttl.get(Endpoint::Person), Duration::from_secs(60));
+        assert_eq!(ttl.get(Endpoint::Movie), Duration::from_secs(3600));
+        assert_eq!(ttl.get(Endpoint::Configuration), Duration::from_secs(86400));
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let cache = MemoryCache::default();
+        let person = CacheKey::new(Endpoint::Person, "/3/person/19498");
+        let movie = CacheKey::new(Endpoint::Movie, "/3/movie/870028");
+
+        cache.insert(person.clone(), b"{}".to_vec(), Duration::from_secs(60));
+        cache.insert(movie.clone(), b"{}".to_vec(), Duration::from_secs(60));
+        cache.invalidate(Endpoint::Person);
+
+        assert_eq!(cache.keys(), vec![movie]);
+    }
+}
@@ -0,0 +1,374 @@
+use crate::cache::{CacheBackend, CacheKey, Endpoint};
+use std::fs::{File, create_dir_all, read, read_dir, remove_file, rename, write};
+use std::io::{BufRead, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+const TEMP_EXTENSION: &str = "tmp";
+/// The number of writes between each purge of expired files
+const PURGE_INTERVAL: u64 = 100;
+/// How long after it was last modified a temporary file is assumed to have been abandoned
+const TEMP_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// Cache persisting each value as a file within a directory, such that it survives restarts.
+///
+/// Each file starts with the unix time at which it expires and its key, each on their own line,
+/// followed by the value. Expired files, and temporary files left by interrupted writes, are
+/// removed when the cache is created and every [`PURGE_INTERVAL`] writes thereafter.
+#[derive(Debug)]
+pub struct DiskCache {
+    directory: PathBuf,
+    /// Distinguishes the temporary files of concurrent writes
+    writes: AtomicU64,
+}
+
+impl DiskCache {
+    /// A cache within `directory`, which is created should it not exist
+    pub fn new<P: Into<PathBuf>>(directory: P) -> std::io::Result<Self> {
+        let directory = directory.into();
+        create_dir_all(directory.as_path())?;
+
+        let cache = Self {
+            directory,
+            writes: AtomicU64::new(0),
+        };
+        cache.purge_at(SystemTime::now());
+
+        Ok(cache)
+    }
+
+    pub fn directory(&self) -> &Path {
+        self.directory.as_path()
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.directory
+            .join(format!("{}-{:016x}", key.endpoint, fnv1a(key.resource())))
+    }
+
+    fn get_at(&self, key: &CacheKey, now: SystemTime) -> Option<Vec<u8>> {
+        let path = self.path(key);
+
+        let contents = match read(path.as_path()) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return None,
+            Err(error) => {
+                warn!("unable to read cached {key} at {}: {error}", path.display());
+                return None;
+            }
+        };
+
+        let (expires, cached_key, value) = parse(contents.as_slice())?;
+
+        if cached_key != *key {
+            return None;
+        }
+
+        if expires <= now {
+            self.remove(key);
+            return None;
+        }
+
+        Some(value.to_vec())
+    }
+
+    fn insert_at(&self, key: CacheKey, value: Vec<u8>, expires: SystemTime) {
+        let path = self.path(&key);
+        let expires = expires
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut contents = format!("{expires}\n{} {}\n", key.endpoint, key.resource).into_bytes();
+        contents.extend(value);
+
+        let write_id = self.writes.fetch_add(1, Ordering::Relaxed);
+        let temp_path = path.with_extension(format!("{write_id}.{TEMP_EXTENSION}"));
+
+        if let Err(error) = write(temp_path.as_path(), contents)
+            .and_then(|()| rename(temp_path.as_path(), path.as_path()))
+        {
+            warn!("unable to cache {key} at {}: {error}", path.display());
+            let _ = remove_file(temp_path);
+        }
+
+        if (write_id + 1).is_multiple_of(PURGE_INTERVAL) {
+            self.purge_at(SystemTime::now());
+        }
+    }
+
+    /// Removes the files which have expired by `now`, along with temporary files which have not
+    /// been modified for [`TEMP_MAX_AGE`]
+    fn purge_at(&self, now: SystemTime) {
+        let entries = match read_dir(self.directory.as_path()) {
+            Ok(entries) => entries,
+            Err(error) => {
+                warn!(
+                    "unable to read the cache directory {}: {error}",
+                    self.directory.display()
+                );
+                return;
+            }
+        };
+
+        for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+            let purge = if path
+                .extension()
+                .is_some_and(|extension| extension == TEMP_EXTENSION)
+            {
+                path.metadata()
+                    .and_then(|metadata| metadata.modified())
+                    .is_ok_and(|modified| modified + TEMP_MAX_AGE <= now)
+            } else {
+                Self::read_header(path.as_path()).is_some_and(|(expires, _)| expires <= now)
+            };
+
+            if purge && let Err(error) = remove_file(path.as_path()) {
+                warn!(
+                    "unable to remove {} from the cache: {error}",
+                    path.display()
+                );
+            }
+        }
+    }
+
+    /// The expiry and key of the file at `path`
+    fn read_header(path: &Path) -> Option<(SystemTime, CacheKey)> {
+        let mut reader = BufReader::new(File::open(path).ok()?);
+        let mut header = Vec::new();
+
+        for _ in 0..2 {
+            reader.read_until(b'\n', &mut header).ok()?;
+        }
+
+        let (expires, key, _) = parse(header.as_slice())?;
+
+        Some((expires, key))
+    }
+
+    fn keys_at(&self, now: SystemTime) -> Vec<CacheKey> {
+        let entries = match read_dir(self.directory.as_path()) {
+            Ok(entries) => entries,
+            Err(error) => {
+                warn!(
+                    "unable to read the cache directory {}: {error}",
+                    self.directory.display()
+                );
+                return Vec::new();
+            }
+        };
+
+        let mut keys = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_none_or(|extension| extension != TEMP_EXTENSION)
+            })
+            .filter_map(|path| Self::read_header(path.as_path()))
+            .filter(|(expires, _)| *expires > now)
+            .map(|(_, key)| key)
+            .collect::<Vec<_>>();
+
+        keys.sort_by(|a, b| (a.endpoint, a.resource()).cmp(&(b.endpoint, b.resource())));
+        keys
+    }
+}
+
+impl CacheBackend for DiskCache {
+    fn get(&self, key: &CacheKey) -> Option<Vec<u8>> {
+        self.get_at(key, SystemTime::now())
+    }
+
+    fn insert(&self, key: CacheKey, value: Vec<u8>, ttl: Duration) {
+        self.insert_at(key, value, SystemTime::now() + ttl);
+    }
+
+    fn remove(&self, key: &CacheKey) -> bool {
+        let path = self.path(key);
+
+        match Self::read_header(path.as_path()) {
+            Some((_, cached_key)) if cached_key == *key => remove_file(path).is_ok(),
+            _ => false,
+        }
+    }
+
+    fn keys(&self) -> Vec<CacheKey> {
+        self.keys_at(SystemTime::now())
+    }
+
+    fn clear(&self) {
+        for key in self.keys_at(UNIX_EPOCH) {
+            self.remove(&key);
+        }
+    }
+}
+
+/// Splits the contents of a cache file into its expiry, key and value
+fn parse(contents: &[u8]) -> Option<(SystemTime, CacheKey, &[u8])> {
+    let mut parts = contents.splitn(3, |byte| *byte == b'\n');
+
+    let expires = std::str::from_utf8(parts.next()?)
+        .ok()?
+        .parse::<u64>()
+        .ok()?;
+    let (endpoint, resource) = std::str::from_utf8(parts.next()?).ok()?.split_once(' ')?;
+    let value = parts.next().unwrap_or_default();
+
+    Some((
+        UNIX_EPOCH + Duration::from_secs(expires),
+        CacheKey::new(Endpoint::from_str(endpoint).ok()?, resource),
+        value,
+    ))
+}
+
+/// The 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+/// hash of `s`, which unlike [`std::hash::DefaultHasher`] is stable across releases of Rust
+fn fnv1a(s: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    s.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn init(name: &str) -> DiskCache {
+        let directory =
+            std::env::temp_dir().join(format!("tmdb-cache-{name}-{}", std::process::id()));
+        let _ = remove_dir_all(directory.as_path());
+
+        DiskCache::new(directory).unwrap()
+    }
+
+    fn key(person_id: i32) -> CacheKey {
+        CacheKey::new(
+            Endpoint::Person,
+            format!("/3/person/{person_id}?language=de-DE"),
+        )
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_get() {
+        let cache = init("get");
+
+        assert_eq!(cache.get(&key(1)), None);
+
+        cache.insert(key(1), b"{\n}".to_vec(), TTL);
+        assert_eq!(cache.get(&key(1)), Some(b"{\n}".to_vec()));
+
+        cache.insert(key(1), b"{}".to_vec(), TTL);
+        assert_eq!(cache.get(&key(1)), Some(b"{}".to_vec()));
+
+        remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_persists() {
+        let cache = init("persists");
+        cache.insert(key(1), b"one".to_vec(), TTL);
+
+        let cache = DiskCache::new(cache.directory()).unwrap();
+        assert_eq!(cache.get(&key(1)), Some(b"one".to_vec()));
+
+        remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_expiry() {
+        let cache = init("expiry");
+        let now = SystemTime::now();
+
+        cache.insert_at(key(1), b"one".to_vec(), now + TTL);
+
+        assert_eq!(cache.get_at(&key(1), now), Some(b"one".to_vec()));
+        assert!(cache.keys_at(now + TTL).is_empty());
+        assert_eq!(cache.get_at(&key(1), now + TTL), None);
+        assert!(cache.keys_at(UNIX_EPOCH).is_empty());
+
+        remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_keys_remove_and_clear() {
+        let cache = init("keys");
+
+        cache.insert(key(2), b"two".to_vec(), TTL);
+        cache.insert(key(1), b"one".to_vec(), TTL);
+        assert_eq!(cache.keys(), vec![key(1), key(2)]);
+
+        assert!(cache.remove(&key(1)));
+        assert!(!cache.remove(&key(1)));
+        assert_eq!(cache.keys(), vec![key(2)]);
+
+        cache.clear();
+        assert!(cache.keys().is_empty());
+
+        remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_purge_on_new() {
+        let cache = init("purge-new");
+        let now = SystemTime::now();
+
+        cache.insert_at(key(1), b"one".to_vec(), now - TTL);
+        cache.insert_at(key(2), b"two".to_vec(), now + TTL);
+
+        let stale_temp = cache
+            .directory()
+            .join(format!("person-1.0.{TEMP_EXTENSION}"));
+        File::create(stale_temp.as_path())
+            .unwrap()
+            .set_modified(now - TEMP_MAX_AGE)
+            .unwrap();
+        let temp = cache
+            .directory()
+            .join(format!("person-2.1.{TEMP_EXTENSION}"));
+        File::create(temp.as_path()).unwrap();
+
+        let cache = DiskCache::new(cache.directory()).unwrap();
+
+        assert!(!cache.path(&key(1)).exists());
+        assert_eq!(cache.get(&key(2)), Some(b"two".to_vec()));
+        assert!(!stale_temp.exists());
+        assert!(temp.exists());
+
+        remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_purge_on_insert() {
+        let cache = init("purge-insert");
+        let now = SystemTime::now();
+
+        cache.insert_at(key(0), b"zero".to_vec(), now - TTL);
+
+        for person_id in 1..PURGE_INTERVAL as i32 - 1 {
+            cache.insert_at(key(person_id), b"one".to_vec(), now + TTL);
+        }
+        assert!(cache.path(&key(0)).exists());
+
+        cache.insert_at(key(1), b"one".to_vec(), now + TTL);
+        assert!(!cache.path(&key(0)).exists());
+        assert_eq!(cache.keys().len(), PURGE_INTERVAL as usize - 2);
+
+        remove_dir_all(cache.directory()).unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+use crate::cache::{CacheBackend, CacheKey};
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct Entry {
+    value: Vec<u8>,
+    expires: Instant,
+    /// Position within [`Entries::recency`]
+    used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    entries: HashMap<CacheKey, Entry>,
+    /// Keys ordered from least to most recently used
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+}
+
+impl Entries {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &CacheKey) -> Option<Entry> {
+        let entry = self.entries.remove(key)?;
+        self.recency.remove(&entry.used);
+
+        Some(entry)
+    }
+}
+
+/// In-process cache which, once full, evicts the least recently used value
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: NonZeroUsize,
+    entries: Mutex<Entries>,
+}
+
+impl MemoryCache {
+    pub const DEFAULT_CAPACITY: NonZeroUsize = NonZeroUsize::new(1000).unwrap();
+
+    /// A cache holding at most `capacity` values
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    fn entries(&self) -> MutexGuard<'_, Entries> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn get_at(&self, key: &CacheKey, now: Instant) -> Option<Vec<u8>> {
+        let mut entries = self.entries();
+        let tick = entries.next_tick();
+
+        let Entries {
+            entries, recency, ..
+        } = &mut *entries;
+
+        let entry = entries.get_mut(key)?;
+
+        if entry.expires <= now {
+            recency.remove(&entry.used);
+            entries.remove(key);
+            return None;
+        }
+
+        recency.remove(&entry.used);
+        recency.insert(tick, key.clone());
+        entry.used = tick;
+
+        Some(entry.value.clone())
+    }
+
+    fn insert_at(&self, key: CacheKey, value: Vec<u8>, expires: Instant, now: Instant) {
+        let mut entries = self.entries();
+        entries.remove(&key);
+
+        if entries.entries.len() >= self.capacity.get() {
+            entries.entries.retain(|_, entry| entry.expires > now);
+
+            let Entries {
+                entries, recency, ..
+            } = &mut *entries;
+            recency.retain(|_, key| entries.contains_key(key));
+        }
+
+        while entries.entries.len() >= self.capacity.get() {
+            let Some((_, key)) = entries.recency.pop_first() else {
+                break;
+            };
+
+            entries.entries.remove(&key);
+        }
+
+        let used = entries.next_tick();
+        entries.recency.insert(used, key.clone());
+        entries.entries.insert(
+            key,
+            Entry {
+                value,
+                expires,
+                used,
+            },
+        );
+    }
+
+    /// Unexpired keys, from least to most recently used
+    fn keys_at(&self, now: Instant) -> Vec<CacheKey> {
+        let entries = self.entries();
+
+        entries
+            .recency
+            .values()
+            .filter(|key| entries.entries[*key].expires > now)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Default for MemoryCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<Vec<u8>> {
+        self.get_at(key, Instant::now())
+    }
+
+    fn insert(&self, key: CacheKey, value: Vec<u8>, ttl: Duration) {
+        let now = Instant::now();
+        self.insert_at(key, value, now + ttl, now);
+    }
+
+    fn remove(&self, key: &CacheKey) -> bool {
+        self.entries().remove(key).is_some()
+    }
+
+    fn keys(&self) -> Vec<CacheKey> {
+        self.keys_at(Instant::now())
+    }
+
+    fn clear(&self) {
+        let mut entries = self.entries();
+        entries.entries.clear();
+        entries.recency.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::Endpoint;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn key(person_id: i32) -> CacheKey {
+        CacheKey::new(Endpoint::Person, format!("/3/person/{person_id}"))
+    }
+
+    #[test]
+    fn test_get() {
+        let cache = MemoryCache::default();
+
+        assert_eq!(cache.get(&key(1)), None);
+
+        cache.insert(key(1), b"one".to_vec(), TTL);
+        assert_eq!(cache.get(&key(1)), Some(b"one".to_vec()));
+
+        cache.insert(key(1), b"uno".to_vec(), TTL);
+        assert_eq!(cache.get(&key(1)), Some(b"uno".to_vec()));
+        assert_eq!(cache.keys(), vec![key(1)]);
+    }
+
+    #[test]
+    fn test_expiry() {
+        let cache = MemoryCache::default();
+        let now = Instant::now();
+
+        cache.insert_at(key(1), b"one".to_vec(), now + TTL, now);
+
+        assert_eq!(cache.get_at(&key(1), now + TTL / 2), Some(b"one".to_vec()));
+        assert!(cache.keys_at(now + TTL).is_empty());
+        assert_eq!(cache.get_at(&key(1), now + TTL), None);
+        assert!(!cache.remove(&key(1)));
+    }
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let cache = MemoryCache::new(NonZeroUsize::new(2).unwrap());
+
+        cache.insert(key(1), b"one".to_vec(), TTL);
+        cache.insert(key(2), b"two".to_vec(), TTL);
+        cache.get(&key(1));
+        cache.insert(key(3), b"three".to_vec(), TTL);
+
+        assert_eq!(cache.keys(), vec![key(1), key(3)]);
+        assert_eq!(cache.get(&key(2)), None);
+    }
+
+    #[test]
+    fn test_evict_expired_first() {
+        let cache = MemoryCache::new(NonZeroUsize::new(2).unwrap());
+        let now = Instant::now();
+
+        cache.insert_at(key(1), b"one".to_vec(), now + TTL * 2, now);
+        cache.insert_at(key(2), b"two".to_vec(), now + TTL / 2, now);
+        cache.insert_at(key(3), b"three".to_vec(), now + TTL * 2, now + TTL);
+
+        assert_eq!(cache.keys_at(now + TTL), vec![key(1), key(3)]);
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let cache = MemoryCache::default();
+
+        cache.insert(key(1), b"one".to_vec(), TTL);
+        cache.insert(key(2), b"two".to_vec(), TTL);
+
+        assert!(cache.remove(&key(1)));
+        assert_eq!(cache.keys(), vec![key(2)]);
+
+        cache.clear();
+        assert!(cache.keys().is_empty());
+    }
+}
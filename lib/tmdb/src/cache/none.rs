@@ -0,0 +1,24 @@
+use crate::cache::{CacheBackend, CacheKey};
+use std::time::Duration;
+
+/// Caches nothing, such that every call of an endpoint results in a request
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NoCache;
+
+impl CacheBackend for NoCache {
+    fn get(&self, _key: &CacheKey) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn insert(&self, _key: CacheKey, _value: Vec<u8>, _ttl: Duration) {}
+
+    fn remove(&self, _key: &CacheKey) -> bool {
+        false
+    }
+
+    fn keys(&self) -> Vec<CacheKey> {
+        Vec::new()
+    }
+
+    fn clear(&self) {}
+}
@@ -1,9 +1,16 @@
 use crate::Tmdb;
 use crate::api_version::ApiVersion;
+use crate::cache::{CacheKey, Endpoint};
+use crate::localisation::Localisation;
 use crate::models::v3::tmdb_error::{TmdbError, UnknownTmdbError};
+use crate::retry;
+use chrono::Utc;
+use http::StatusCode;
 use reqwest::{Method, Response};
-use std::fmt::Display;
-use url::ParseError;
+use serde::de::DeserializeOwned;
+use std::time::Instant;
+use tracing::{trace, warn};
+use url::{ParseError, Position, Url};
 
 pub mod v3;
 
@@ -18,35 +25,224 @@ pub enum RequestError {
     TmdbError(#[from] TmdbError),
     #[error("unknown tmdb error: {0}")]
     UnknownTmdbError(#[from] UnknownTmdbError),
+    #[error("deserialisation error: {0}")]
+    Deserialise(#[from] serde_json::Error),
 }
 
-pub(crate) async fn request<P: AsRef<str> + Display>(
+/// The url of `path`, appending the `language` and `region` query parameters of `localisation`
+/// or, if not provided, the default [`Localisation`] of the client
+fn request_url<P: AsRef<str>>(
     tmdb: &Tmdb,
     path: P,
-    method: Method,
-) -> Result<Response, RequestError> {
-    use secrecy::ExposeSecret;
-
-    let url = tmdb
+    localisation: Option<&Localisation>,
+) -> Result<Url, RequestError> {
+    let mut url = tmdb
         .api_url
         .join(ApiVersion::V3.base_path())
         .and_then(|url| url.join(path.as_ref()))?;
 
-    tmdb.http_client
-        .request(method, url)
-        .bearer_auth(tmdb.token.expose_secret())
-        .send()
+    let mut query_pairs = localisation
+        .unwrap_or(&tmdb.localisation)
+        .query_pairs()
+        .peekable();
+
+    if query_pairs.peek().is_some() {
+        url.query_pairs_mut().extend_pairs(query_pairs);
+    }
+
+    Ok(url)
+}
+
+/// The cached value of `key`, removing it should it no longer deserialise
+fn cached<T: DeserializeOwned>(tmdb: &Tmdb, key: &CacheKey) -> Option<T> {
+    let value = tmdb.cache.get(key)?;
+
+    match serde_json::from_slice::<T>(value.as_slice()) {
+        Ok(value) => {
+            trace!("cache hit {key}");
+            Some(value)
+        }
+        Err(error) => {
+            warn!("unable to deserialise cached {key}, removing: {error}");
+            tmdb.cache.remove(key);
+            None
+        }
+    }
+}
+
+/// Performs a get request on `path`, see [`request_url`], deserialising the response.
+///
+/// Successful responses are cached by the [`CacheBackend`](crate::cache::CacheBackend) of the
+/// client for the [ttl](crate::Tmdb::cache_ttl) of `endpoint`. Concurrent misses of the same
+/// response wait for the first to complete, making a single request should it succeed.
+pub(crate) async fn get_cached<T: DeserializeOwned, P: AsRef<str>>(
+    tmdb: &Tmdb,
+    endpoint: Endpoint,
+    path: P,
+    localisation: Option<&Localisation>,
+) -> Result<T, RequestError> {
+    let url = request_url(tmdb, path, localisation)?;
+    let key = CacheKey::new(endpoint, &url[Position::BeforePath..]);
+    let ttl = tmdb.cache_ttl.get(endpoint);
+
+    let _in_flight = if ttl.is_zero() {
+        None
+    } else {
+        if let Some(value) = cached(tmdb, &key) {
+            return Ok(value);
+        }
+
+        let in_flight = tmdb.in_flight.lock(&key).await;
+
+        // cached by a concurrent request while waiting
+        if let Some(value) = cached(tmdb, &key) {
+            return Ok(value);
+        }
+
+        Some(in_flight)
+    };
+
+    trace!("cache miss {key}");
+
+    let response = send(tmdb, Method::GET, url).await?;
+
+    match response.status() {
+        StatusCode::OK => (),
+        _ => {
+            return Err(match TmdbError::try_from_response(response).await {
+                Ok(error) => error.into(),
+                Err(error) => error.into(),
+            });
+        }
+    }
+
+    let body = response.bytes().await.map_err(RequestError::Reqwest)?;
+    let value = serde_json::from_slice::<T>(body.as_ref())?;
+
+    if !ttl.is_zero() {
+        tmdb.cache.insert(key, body.to_vec(), ttl);
+    }
+
+    Ok(value)
+}
+
+/// Performs a request on `url`.
+///
+/// Requests are queued behind the [`ThrottleConfig`](crate::throttle::ThrottleConfig) of the
+/// client. Idempotent requests failing with a retryable [`TmdbError`], or failing to connect or
+/// timing out, are retried according to the [`RetryPolicy`](crate::retry::RetryPolicy) of the
+/// client, each attempt being timed out once its max elapsed time has passed.
+async fn send(tmdb: &Tmdb, method: Method, url: Url) -> Result<Response, RequestError> {
+    use secrecy::ExposeSecret;
+
+    let start = Instant::now();
+    let mut retry = 0;
+
+    loop {
+        let permit = tmdb.throttle.acquire().await;
+
+        let remaining = tmdb
+            .retry_policy
+            .max_elapsed
+            .saturating_sub(start.elapsed());
+        let timeout = tmdb
+            .timeout
+            .map_or(remaining, |timeout| timeout.min(remaining));
+
+        let response = match tmdb
+            .http_client
+            .request(method.clone(), url.clone())
+            .bearer_auth(tmdb.token.expose_secret())
+            .timeout(timeout)
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(error) if method.is_idempotent() && (error.is_connect() || error.is_timeout()) => {
+                drop(permit);
+
+                match tmdb.retry_policy.backoff(retry, None, start.elapsed()) {
+                    Some(backoff) => {
+                        warn!("{error}, retrying {url} in {backoff:?}");
+                        tokio::time::sleep(backoff).await;
+                        retry += 1;
+                        continue;
+                    }
+                    None => return Err(RequestError::Reqwest(error)),
+                }
+            }
+            Err(error) => return Err(RequestError::Reqwest(error)),
+        };
+
+        let status = response.status();
+
+        if !method.is_idempotent() || !(status.is_client_error() || status.is_server_error()) {
+            return Ok(response);
+        }
+
+        let (error, response) = retryable_error(response).await?;
+        drop(permit);
+
+        let retry_after = retry::retry_after(response.headers(), Utc::now());
+
+        let backoff = match error {
+            Some(error) => match tmdb
+                .retry_policy
+                .backoff(retry, retry_after, start.elapsed())
+            {
+                Some(backoff) => {
+                    warn!("{error} ({status}), retrying {url} in {backoff:?}");
+                    backoff
+                }
+                None => return Ok(response),
+            },
+            None => return Ok(response),
+        };
+
+        tokio::time::sleep(backoff).await;
+        retry += 1;
+    }
+}
+
+/// Buffers the body of an unsuccessful `response` to determine whether it is a retryable
+/// [`TmdbError`], returning an equivalent response for any further processing
+async fn retryable_error(
+    response: Response,
+) -> Result<(Option<TmdbError>, Response), RequestError> {
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let body = response.bytes().await.map_err(RequestError::Reqwest)?;
+
+    let rebuild = || {
+        let mut response = http::Response::new(body.clone());
+        *response.status_mut() = status;
+        *response.version_mut() = version;
+        *response.headers_mut() = headers.clone();
+
+        Response::from(response)
+    };
+
+    let error = TmdbError::try_from_response(rebuild())
         .await
-        .map_err(RequestError::Reqwest)
+        .ok()
+        .filter(TmdbError::is_retryable);
+
+    Ok((error, rebuild()))
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
+    use crate::retry::RetryPolicy;
+    use crate::throttle::ThrottleConfig;
     use http::StatusCode;
-    use tmdb_test_utils::api::misc::status_codes::mock_get_ok;
-    use tmdb_test_utils::api::v3::errors::mock_invalid_id;
-    use tmdb_test_utils::mockito::ServerGuard;
+    use std::num::{NonZeroU32, NonZeroUsize};
+    use std::time::{Duration, Instant};
+    use tmdb_test_utils::api::misc::status_codes::{mock_get_ok, mock_status_code_at_path};
+    use tmdb_test_utils::api::v3::errors::{mock_invalid_id, mock_rate_limited_at_path};
+    use tmdb_test_utils::api::v3::person::mock_get_person_details;
+    use tmdb_test_utils::mockito::{Mock, ServerGuard};
     use tmdb_test_utils::start_mock_tmdb_api;
 
     async fn init() -> (Tmdb, ServerGuard) {
@@ -58,12 +254,35 @@ pub(crate) mod tests {
         (tmdb, server)
     }
 
+    const PATH: &str = "/status_codes/200";
+
+    async fn request<P: AsRef<str>>(
+        tmdb: &Tmdb,
+        path: P,
+        method: Method,
+        localisation: Option<&Localisation>,
+    ) -> Result<Response, RequestError> {
+        let url = request_url(tmdb, path, localisation)?;
+
+        send(tmdb, method, url).await
+    }
+
+    /// Retries immediately unless told otherwise by `Retry-After`
+    fn immediate_retry_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::from_secs(5),
+            max_elapsed: Duration::from_secs(20),
+        }
+    }
+
     #[tokio::test]
     async fn test_request() {
         let (tmdb, mut server) = init().await;
         let (mock, path) = mock_get_ok(&mut server).await;
 
-        let response = request(&tmdb, path, Method::GET).await.unwrap();
+        let response = request(&tmdb, path, Method::GET, None).await.unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
         mock.assert();
@@ -74,10 +293,217 @@ pub(crate) mod tests {
         let (tmdb, mut server) = init().await;
         let (mock, path) = mock_invalid_id(&mut server).await;
 
-        let response = request(&tmdb, path, Method::GET).await.unwrap();
+        let response = request(&tmdb, path, Method::GET, None).await.unwrap();
         let error = TmdbError::try_from_response(response).await;
 
         assert_eq!(error, Ok(TmdbError::InvalidId));
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_request_retry_rate_limited() {
+        let (mut tmdb, mut server) = init().await;
+        tmdb.set_retry_policy(immediate_retry_policy(3));
+
+        let rate_limited_mock =
+            mock_rate_limited_at_path(&mut server, Method::GET, PATH, None).await;
+        let ok_mock =
+            mock_status_code_at_path(&mut server, PATH, Method::GET, StatusCode::OK).await;
+
+        let response = request(&tmdb, PATH, Method::GET, None).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        rate_limited_mock.assert();
+        ok_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_honours_retry_after() {
+        let (mut tmdb, mut server) = init().await;
+        tmdb.set_retry_policy(immediate_retry_policy(1));
+
+        let rate_limited_mock =
+            mock_rate_limited_at_path(&mut server, Method::GET, PATH, Some("1")).await;
+        let ok_mock =
+            mock_status_code_at_path(&mut server, PATH, Method::GET, StatusCode::OK).await;
+
+        let start = Instant::now();
+        let response = request(&tmdb, PATH, Method::GET, None).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        rate_limited_mock.assert();
+        ok_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_exhausted() {
+        let (mut tmdb, mut server) = init().await;
+        tmdb.set_retry_policy(immediate_retry_policy(1));
+
+        let mut rate_limited_mocks = Vec::new();
+        for _ in 0..2 {
+            rate_limited_mocks
+                .push(mock_rate_limited_at_path(&mut server, Method::GET, PATH, None).await);
+        }
+        let ok_mock =
+            mock_status_code_at_path(&mut server, PATH, Method::GET, StatusCode::OK).await;
+
+        let response = request(&tmdb, PATH, Method::GET, None).await.unwrap();
+        let error = TmdbError::try_from_response(response).await;
+
+        assert_eq!(error, Ok(TmdbError::RateLimited));
+        rate_limited_mocks.iter().for_each(Mock::assert);
+        assert!(!ok_mock.matched());
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_non_idempotent() {
+        let (mut tmdb, mut server) = init().await;
+        tmdb.set_retry_policy(immediate_retry_policy(3));
+
+        let rate_limited_mock =
+            mock_rate_limited_at_path(&mut server, Method::POST, PATH, None).await;
+        let ok_mock =
+            mock_status_code_at_path(&mut server, PATH, Method::POST, StatusCode::OK).await;
+
+        let response = request(&tmdb, PATH, Method::POST, None).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        rate_limited_mock.assert();
+        assert!(!ok_mock.matched());
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_disabled() {
+        let (mut tmdb, mut server) = init().await;
+        tmdb.set_retry_policy(RetryPolicy::disabled());
+
+        let rate_limited_mock =
+            mock_rate_limited_at_path(&mut server, Method::GET, PATH, None).await;
+        let _ok_mock =
+            mock_status_code_at_path(&mut server, PATH, Method::GET, StatusCode::OK).await;
+
+        let response = request(&tmdb, PATH, Method::GET, None).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        rate_limited_mock.assert();
+    }
+
+    /// The url of a local port which refuses connections
+    async fn refused_api_url() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+
+        format!("http://{address}/")
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_connect_error() {
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(refused_api_url().await.as_str())
+            .unwrap();
+        tmdb.set_retry_policy(RetryPolicy {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(200),
+            ..RetryPolicy::default()
+        });
+
+        let start = Instant::now();
+        let error = request(&tmdb, PATH, Method::GET, None).await.unwrap_err();
+
+        assert!(matches!(error, RequestError::Reqwest(error) if error.is_connect()));
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_connect_error_non_idempotent() {
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(refused_api_url().await.as_str())
+            .unwrap();
+        tmdb.set_retry_policy(RetryPolicy {
+            max_retries: 1,
+            initial_backoff: Duration::from_secs(2),
+            ..RetryPolicy::default()
+        });
+
+        let start = Instant::now();
+        let error = request(&tmdb, PATH, Method::POST, None).await.unwrap_err();
+
+        assert!(matches!(error, RequestError::Reqwest(error) if error.is_connect()));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_max_elapsed() {
+        let (mut tmdb, mut server) = init().await;
+        tmdb.set_retry_policy(RetryPolicy {
+            max_elapsed: Duration::from_secs(1),
+            ..immediate_retry_policy(3)
+        });
+
+        let rate_limited_mock =
+            mock_rate_limited_at_path(&mut server, Method::GET, PATH, Some("2")).await;
+        let ok_mock =
+            mock_status_code_at_path(&mut server, PATH, Method::GET, StatusCode::OK).await;
+
+        let start = Instant::now();
+        let response = request(&tmdb, PATH, Method::GET, None).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(start.elapsed() < Duration::from_secs(1));
+        rate_limited_mock.assert();
+        assert!(!ok_mock.matched());
+    }
+
+    #[tokio::test]
+    async fn test_request_throttled() {
+        let mut server = start_mock_tmdb_api().await;
+        let mut tmdb = Tmdb::with_throttle(
+            reqwest::Client::new(),
+            secrecy::SecretString::from("THIS_IS_A_TEST"),
+            ThrottleConfig {
+                max_concurrent: NonZeroUsize::new(2),
+                requests_per_second: NonZeroU32::new(10),
+            },
+        );
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let _mock = mock_status_code_at_path(&mut server, PATH, Method::GET, StatusCode::OK).await;
+
+        let start = Instant::now();
+        let responses = tokio::join!(
+            request(&tmdb, PATH, Method::GET, None),
+            request(&tmdb, PATH, Method::GET, None),
+            request(&tmdb, PATH, Method::GET, None),
+        );
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        for response in [responses.0, responses.1, responses.2] {
+            assert_eq!(response.unwrap().status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_cached_concurrent_misses() {
+        const PERSON_ID: i32 = 19498;
+
+        let (tmdb, mut server) = init().await;
+        let mock = mock_get_person_details(&mut server, PERSON_ID)
+            .await
+            .expect(1);
+        let localisation = Localisation::default();
+
+        let responses = tokio::join!(
+            v3::person::get(&tmdb, PERSON_ID, &localisation),
+            v3::person::get(&tmdb, PERSON_ID, &localisation),
+            v3::person::get(&tmdb, PERSON_ID, &localisation),
+        );
+
+        for response in [responses.0, responses.1, responses.2] {
+            assert_eq!(response.unwrap().id, PERSON_ID);
+        }
+        mock.assert();
+    }
 }
@@ -1 +1,6 @@
+pub mod configuration;
+pub mod find;
+pub mod movie;
 pub mod person;
+pub mod search;
+pub mod tv;
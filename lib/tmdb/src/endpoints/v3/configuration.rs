@@ -0,0 +1,47 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::configuration::Configuration;
+use tracing::instrument;
+
+/// [GET: Configuration Details](https://developer.themoviedb.org/reference/configuration-details)
+///
+/// Performs a get request on the `configuration` endpoint.
+///
+/// ## NOTE
+/// The Configuration struct is not an exhaustive representation of the data provided by the api.
+#[instrument(level = "trace", name = "configuration::get", skip(tmdb))]
+pub async fn get(tmdb: &Tmdb) -> Result<Configuration, RequestError> {
+    get_cached(tmdb, Endpoint::Configuration, "configuration", None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    #[tokio::test]
+    async fn test_get() {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_configuration(&mut server).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb).await.unwrap();
+        let images = response.images;
+
+        assert_eq!(
+            images.secure_base_url.as_str(),
+            "https://image.tmdb.org/t/p/"
+        );
+        assert_eq!(
+            images.poster_sizes,
+            ["w92", "w154", "w185", "w342", "w500", "w780", "original"]
+        );
+        assert_eq!(images.profile_sizes, ["w45", "w185", "h632", "original"]);
+
+        mock.assert();
+    }
+}
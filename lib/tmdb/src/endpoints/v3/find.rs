@@ -0,0 +1,62 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::external_source::ExternalSource;
+use crate::models::v3::find_results::FindResults;
+use tracing::instrument;
+use url::form_urlencoded::byte_serialize;
+
+/// [GET: Find By ID](https://developer.themoviedb.org/reference/find-by-id)
+///
+/// Performs a get request on the `find/{external_id}` endpoint.
+///
+/// ## NOTE
+/// The FindResults struct is not an exhaustive representation of the data provided by the api.
+#[instrument(level = "trace", name = "find::get", skip(tmdb))]
+pub async fn get(
+    tmdb: &Tmdb,
+    external_id: &str,
+    external_source: ExternalSource,
+) -> Result<FindResults, RequestError> {
+    let external_id = byte_serialize(external_id.as_bytes()).collect::<String>();
+    let path = format!("find/{external_id}?external_source={external_source}");
+
+    get_cached(tmdb, Endpoint::Find, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tmdb_test_utils::api::v3::find::mock_get_find;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    async fn find_imdb_id(imdb_id: &str) -> FindResults {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_find(&mut server, imdb_id, "imdb_id").await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb, imdb_id, ExternalSource::ImdbId).await.unwrap();
+
+        mock.assert();
+
+        response
+    }
+
+    #[tokio::test]
+    async fn test_get_nm1256532() {
+        let response = find_imdb_id("nm1256532").await;
+
+        assert_eq!(response.person_results.len(), 1);
+        assert_eq!(response.person_results[0].id, 19498);
+        assert_eq!(response.person_results[0].name, "Jon Bernthal");
+    }
+
+    #[tokio::test]
+    async fn test_get_no_results() {
+        let response = find_imdb_id("nm0000000").await;
+
+        assert!(response.person_results.is_empty());
+    }
+}
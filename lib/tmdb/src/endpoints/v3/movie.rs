@@ -0,0 +1,140 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::movie_details::MovieDetails;
+use tracing::instrument;
+
+pub mod release_dates;
+
+/// [GET: Movie Details](https://developer.themoviedb.org/reference/movie-details)
+///
+/// Performs a get request on the `movie/{movie_id}` endpoint.
+///
+/// ## NOTE
+/// The MovieDetails struct is not an exhaustive representation of the data provided by the api.
+#[instrument(level = "trace", name = "movie::get", skip(tmdb))]
+pub async fn get(tmdb: &Tmdb, movie_id: i32) -> Result<MovieDetails, RequestError> {
+    let path = format!("movie/{movie_id}");
+
+    get_cached(tmdb, Endpoint::Movie, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::v3::genres::MovieGenre;
+    use crate::models::v3::movie_status::MovieStatus;
+    use crate::models::v3::production_company::ProductionCompany;
+    use crate::models::v3::spoken_language::SpokenLanguage;
+    use chrono::NaiveDate;
+    use tmdb_test_utils::api::v3::movie::mock_get_movie_details;
+    use tmdb_test_utils::mockito::{Mock, ServerGuard};
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    async fn init(movie_id: i32) -> (Tmdb, ServerGuard, Mock) {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_movie_details(&mut server, movie_id).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        (tmdb, server, mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_870028() {
+        const MOVIE_ID: i32 = 870028;
+        const OVERVIEW: &str = include_str!("../../../tests/assets/api/movie/870028_overview.txt");
+
+        let (tmdb, _server, mock) = init(MOVIE_ID).await;
+        let response = get(&tmdb, MOVIE_ID).await.unwrap();
+
+        assert!(!response.adult);
+        assert_eq!(
+            response.backdrop_path,
+            Some("/rdxdvXQGqCj2Vb7SvTL8gdxlSzI.jpg".to_string())
+        );
+        assert_eq!(response.budget, 80000000);
+        assert_eq!(
+            response.genres,
+            vec![MovieGenre::Crime, MovieGenre::Thriller, MovieGenre::Action]
+        );
+        assert_eq!(
+            response.homepage,
+            Some("https://www.amazonmgmstudios.com/movies/the-accountant-2".to_string())
+        );
+        assert_eq!(response.id, MOVIE_ID);
+        assert_eq!(response.imdb_id, Some("tt7068946".to_string()));
+        assert_eq!(response.original_language, "en");
+        assert_eq!(response.original_title, "The Accountant²");
+        assert_eq!(response.overview, Some(OVERVIEW.to_string()));
+        assert_eq!(response.popularity, 95.1582);
+        assert_eq!(
+            response.poster_path,
+            Some("/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg".to_string())
+        );
+        assert_eq!(response.production_companies.len(), 3);
+        assert_eq!(
+            response.production_companies[1],
+            ProductionCompany {
+                id: 201001,
+                logo_path: None,
+                name: "Artists Equity".to_string(),
+                origin_country: Some("US".to_string()),
+            }
+        );
+        assert_eq!(
+            response.release_date,
+            NaiveDate::parse_from_str("2025-04-23", "%Y-%m-%d").ok()
+        );
+        assert_eq!(response.revenue, 102567000);
+        assert_eq!(response.runtime, Some(132));
+        assert_eq!(
+            response.spoken_languages,
+            vec![
+                SpokenLanguage {
+                    english_name: "English".to_string(),
+                    iso_639_1: "en".to_string(),
+                    name: "English".to_string(),
+                },
+                SpokenLanguage {
+                    english_name: "Spanish".to_string(),
+                    iso_639_1: "es".to_string(),
+                    name: "Español".to_string(),
+                },
+            ]
+        );
+        assert_eq!(response.status, MovieStatus::Released);
+        assert_eq!(
+            response.tagline,
+            Some("Time to settle the accounts.".to_string())
+        );
+        assert_eq!(response.title, "The Accountant²");
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_1481683() {
+        const MOVIE_ID: i32 = 1481683;
+
+        let (tmdb, _server, mock) = init(MOVIE_ID).await;
+        let response = get(&tmdb, MOVIE_ID).await.unwrap();
+
+        assert_eq!(response.backdrop_path, None);
+        assert_eq!(response.budget, 0);
+        assert_eq!(response.homepage, None);
+        assert_eq!(response.imdb_id, None);
+        assert_eq!(response.overview, None);
+        assert_eq!(response.poster_path, None);
+        assert!(response.production_companies.is_empty());
+        assert_eq!(response.release_date, None);
+        assert_eq!(response.runtime, None);
+        assert!(response.spoken_languages.is_empty());
+        assert_eq!(response.status, MovieStatus::Planned);
+        assert_eq!(response.tagline, None);
+        assert_eq!(response.title, "The Accountant 3");
+
+        mock.assert();
+    }
+}
@@ -0,0 +1,94 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::localisation::Localisation;
+use crate::models::v3::release_dates::MovieReleaseDates;
+use tracing::instrument;
+
+/// [GET: Movie Release Dates](https://developer.themoviedb.org/reference/movie-release-dates)
+///
+/// Performs a get request on the `movie/{movie_id}/release_dates` endpoint. The response includes
+/// every region, so the request is not localised.
+#[instrument(level = "trace", name = "movie::release_dates::get", skip(tmdb))]
+pub async fn get(tmdb: &Tmdb, movie_id: i32) -> Result<MovieReleaseDates, RequestError> {
+    let path = format!("movie/{movie_id}/release_dates");
+
+    get_cached(
+        tmdb,
+        Endpoint::MovieReleaseDates,
+        path,
+        Some(&Localisation::default()),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::v3::region::Region;
+    use crate::models::v3::release_dates::ReleaseType;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+    use tmdb_test_utils::api::v3::movie::release_dates::mock_get_movie_release_dates;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    #[tokio::test]
+    async fn test_get_870028() {
+        const MOVIE_ID: i32 = 870028;
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_movie_release_dates(&mut server, MOVIE_ID).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb, MOVIE_ID).await.unwrap();
+
+        assert_eq!(response.id, MOVIE_ID);
+        assert_eq!(response.results.len(), 2);
+
+        let us = &response.results[1];
+        assert_eq!(us.iso_3166_1, "US");
+        assert_eq!(us.release_dates.len(), 3);
+        assert_eq!(us.release_dates[0].certification, None);
+        assert_eq!(us.release_dates[0].note, Some("SXSW".to_string()));
+        assert_eq!(us.release_dates[0].release_type, ReleaseType::Premiere);
+        assert_eq!(us.release_dates[1].certification, Some("R".to_string()));
+
+        let gb = Region::from_str("gb").unwrap();
+        assert_eq!(
+            response.release_date(&gb, ReleaseType::Theatrical),
+            NaiveDate::from_ymd_opt(2025, 4, 25)
+        );
+        assert_eq!(
+            response.release_date(&gb, ReleaseType::Digital),
+            NaiveDate::from_ymd_opt(2025, 6, 18)
+        );
+        assert_eq!(response.release_date(&gb, ReleaseType::Physical), None);
+
+        let fr = Region::from_str("FR").unwrap();
+        assert_eq!(response.release_date(&fr, ReleaseType::Theatrical), None);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_not_localised() {
+        const MOVIE_ID: i32 = 870028;
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_movie_release_dates(&mut server, MOVIE_ID).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+        tmdb.set_localisation(Localisation {
+            language: None,
+            region: Some(Region::from_str("GB").unwrap()),
+        });
+
+        let response = get(&tmdb, MOVIE_ID).await.unwrap();
+
+        assert_eq!(response.id, MOVIE_ID);
+        mock.assert();
+    }
+}
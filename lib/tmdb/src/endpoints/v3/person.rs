@@ -1,59 +1,39 @@
 use crate::Tmdb;
-use crate::endpoints::{RequestError, request};
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::localisation::Localisation;
 use crate::models::v3::person_details::PersonDetails;
-use crate::models::v3::tmdb_error::TmdbError;
-#[cfg(all(feature = "cached", not(test)))]
-use cached::proc_macro::cached;
-use http::StatusCode;
-use reqwest::Method;
-#[cfg(all(feature = "cached", not(test)))]
-use std::time::Duration;
-use tracing::{instrument, trace};
+use tracing::instrument;
 
 pub mod combined_credits;
+pub mod external_ids;
 
 /// [GET: Person Details](https://developer.themoviedb.org/reference/person-details)
 ///
-/// Performs a get request on the `person/{person_id}` endpoint.
-#[cfg_attr(all(feature = "cached", not(test)), cached(
-    time = 3600, // 1 hour
-    time_refresh = false,
-    sync_writes = "by_key",
-    key = "i32",
-    convert = r##"{ person_id }"##,
-    result = true
-))]
+/// Performs a get request on the `person/{person_id}` endpoint, localised by `localisation` and
+/// then the default localisation of `tmdb`.
 #[instrument(level = "trace", name = "person::get", skip(tmdb))]
-pub async fn get(tmdb: &Tmdb, person_id: i32) -> Result<PersonDetails, RequestError> {
-    #[cfg(feature = "cached")]
-    trace!("cache miss");
-
+pub async fn get(
+    tmdb: &Tmdb,
+    person_id: i32,
+    localisation: &Localisation,
+) -> Result<PersonDetails, RequestError> {
     let path = format!("person/{person_id}");
+    let localisation = localisation.or(tmdb.localisation());
 
-    let response = request(tmdb, path, Method::GET).await?;
-
-    match response.status() {
-        StatusCode::OK => (),
-        _ => {
-            return Err(match TmdbError::try_from_response(response).await {
-                Ok(error) => error.into(),
-                Err(error) => error.into(),
-            });
-        }
-    }
-
-    response
-        .json::<PersonDetails>()
-        .await
-        .map_err(RequestError::Reqwest)
+    get_cached(tmdb, Endpoint::Person, path, Some(&localisation)).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::v3::gender::Gender;
+    use crate::models::v3::language::Language;
     use chrono::NaiveDate;
-    use tmdb_test_utils::api::v3::person::mock_get_person_details;
+    use std::str::FromStr;
+    use tmdb_test_utils::api::v3::person::{
+        mock_get_person_details, mock_get_person_details_with_language,
+    };
     use tmdb_test_utils::mockito::{Mock, ServerGuard};
     use tmdb_test_utils::start_mock_tmdb_api;
 
@@ -74,7 +54,9 @@ mod tests {
             include_str!("../../../tests/assets/api/person/19498_biography.txt");
 
         let (tmdb, _server, mock) = init(PERSON_ID).await;
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
 
         assert!(!response.adult);
         assert_eq!(
@@ -118,7 +100,9 @@ mod tests {
         const BIOGRAPHY: &str = include_str!("../../../tests/assets/api/person/956_biography.txt");
 
         let (tmdb, _server, mock) = init(PERSON_ID).await;
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
 
         assert!(!response.adult);
         assert_eq!(
@@ -166,7 +150,9 @@ mod tests {
         const BIOGRAPHY: &str = include_str!("../../../tests/assets/api/person/5_biography.txt");
 
         let (tmdb, _server, mock) = init(PERSON_ID).await;
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
 
         assert!(!response.adult);
         assert_eq!(
@@ -212,7 +198,9 @@ mod tests {
         const PERSON_ID: i32 = 48000;
 
         let (tmdb, _server, mock) = init(PERSON_ID).await;
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
 
         assert!(!response.adult);
         assert!(response.also_known_as.is_empty());
@@ -246,7 +234,9 @@ mod tests {
         const PERSON_ID: i32 = 240990;
 
         let (tmdb, _server, mock) = init(PERSON_ID).await;
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
 
         assert!(!response.adult);
         assert!(response.also_known_as.is_empty());
@@ -265,4 +255,45 @@ mod tests {
 
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_get_19498_localised() {
+        const PERSON_ID: i32 = 19498;
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_person_details_with_language(&mut server, PERSON_ID, "de-DE")
+            .await
+            .expect(2);
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let localisation = Localisation {
+            language: Some(Language::from_str("de-DE").unwrap()),
+            region: None,
+        };
+
+        let response = get(&tmdb, PERSON_ID, &localisation).await.unwrap();
+        assert!(
+            response
+                .biography
+                .unwrap()
+                .starts_with("Jonathan Edward Bernthal ist")
+        );
+
+        tmdb.set_localisation(localisation);
+        tmdb.cache().clear();
+
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
+        assert!(
+            response
+                .biography
+                .unwrap()
+                .starts_with("Jonathan Edward Bernthal ist")
+        );
+
+        mock.assert();
+    }
 }
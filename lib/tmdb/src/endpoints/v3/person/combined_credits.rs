@@ -1,16 +1,11 @@
 use crate::Tmdb;
-use crate::endpoints::{RequestError, request};
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::localisation::Localisation;
 use crate::models::v3::cast::Cast;
 use crate::models::v3::crew::Crew;
-use crate::models::v3::tmdb_error::TmdbError;
-#[cfg(all(feature = "cached", not(test)))]
-use cached::proc_macro::cached;
-use http::StatusCode;
-use reqwest::Method;
 use serde::Deserialize;
-#[cfg(all(feature = "cached", not(test)))]
-use std::time::Duration;
-use tracing::{instrument, trace};
+use tracing::instrument;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct CombinedCredits {
@@ -24,50 +19,40 @@ pub struct CombinedCredits {
 
 /// [GET: Combined Credits](https://developer.themoviedb.org/v3/reference/person-combined-credits)
 ///
-/// Performs a get request on the `person/{person_id}/combined_credits` endpoint.
+/// Performs a get request on the `person/{person_id}/combined_credits` endpoint, localised by
+/// `localisation` and then the default localisation of `tmdb`.
 ///
 /// ## NOTE
 /// The CombinedCredits struct is not an exhaustive representation of the data provided by
 /// the api.
-#[cfg_attr(all(feature = "cached", not(test)), cached(
-    time = 3600, // 1 hour
-    time_refresh = false,
-    sync_writes = "by_key",
-    key = "String",
-    convert = r##"{ person_id.to_string() }"##,
-    result = true
-))]
 #[instrument(level = "trace", name = "combined_credits::get", skip(tmdb))]
-pub async fn get(tmdb: &Tmdb, person_id: &str) -> Result<CombinedCredits, RequestError> {
-    #[cfg(feature = "cached")]
-    trace!("cache miss");
-
+pub async fn get(
+    tmdb: &Tmdb,
+    person_id: &str,
+    localisation: &Localisation,
+) -> Result<CombinedCredits, RequestError> {
     let path = format!("person/{person_id}/combined_credits");
-
-    let response = request(tmdb, path, Method::GET).await?;
-
-    match response.status() {
-        StatusCode::OK => (),
-        _ => {
-            return Err(match TmdbError::try_from_response(response).await {
-                Ok(error) => error.into(),
-                Err(error) => error.into(),
-            });
-        }
-    }
-
-    response
-        .json::<CombinedCredits>()
-        .await
-        .map_err(RequestError::Reqwest)
+    let localisation = localisation.or(tmdb.localisation());
+
+    get_cached(
+        tmdb,
+        Endpoint::PersonCombinedCredits,
+        path,
+        Some(&localisation),
+    )
+    .await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::v3::genres::{MovieGenre, TvGenre};
+    use crate::models::v3::language::Language;
     use chrono::NaiveDate;
-    use tmdb_test_utils::api::v3::person::combined_credits::mock_get_person_combined_credits;
+    use std::str::FromStr;
+    use tmdb_test_utils::api::v3::person::combined_credits::{
+        mock_get_person_combined_credits, mock_get_person_combined_credits_with_language,
+    };
     use tmdb_test_utils::mockito::{Mock, ServerGuard};
     use tmdb_test_utils::start_mock_tmdb_api;
 
@@ -86,7 +71,9 @@ mod tests {
         const PERSON_ID: &str = "19498";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(19498));
 
         let cast = response.cast;
@@ -122,6 +109,14 @@ mod tests {
         );
         assert_eq!(movie.original_language, "en");
         assert_eq!(movie.credit_id, "52fe431bc3a36847f803a9db");
+        assert_eq!(
+            movie.poster_path.as_deref(),
+            Some("/bfTiVc8kxq8uRXeblV3Ct35APGu.jpg")
+        );
+        assert_eq!(
+            movie.backdrop_path.as_deref(),
+            Some("/1PtzrpMbuYg9rfWpEkm6qwN63oW.jpg")
+        );
 
         let tv = match &cast[50] {
             Cast::Tv(cast) => cast,
@@ -150,6 +145,14 @@ mod tests {
         );
         assert_eq!(tv.original_language, "en");
         assert_eq!(tv.credit_id, "5256c6e119c2956ff602e49c");
+        assert_eq!(
+            tv.poster_path.as_deref(),
+            Some("/b34jPzmB0wZy7EjUZoleXOl2RRI.jpg")
+        );
+        assert_eq!(
+            tv.backdrop_path.as_deref(),
+            Some("/9YteO4VWteiPmEbWYJRAeBTQZPD.jpg")
+        );
 
         mock.assert();
     }
@@ -159,7 +162,9 @@ mod tests {
         const PERSON_ID: &str = "19498-no-cast-credits";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(19498));
 
         assert_eq!(response.cast.len(), 0);
@@ -173,7 +178,9 @@ mod tests {
         const PERSON_ID: &str = "956";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(956));
 
         let crew = response.crew;
@@ -253,7 +260,9 @@ mod tests {
         const PERSON_ID: &str = "956-no-crew-credits";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(956));
 
         assert_eq!(response.cast.len(), 13);
@@ -267,7 +276,9 @@ mod tests {
         const PERSON_ID: &str = "5";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(5));
 
         let cast = response.cast;
@@ -309,7 +320,9 @@ mod tests {
         const PERSON_ID: &str = "48000";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(48000));
 
         let cast = response.cast;
@@ -346,7 +359,9 @@ mod tests {
         const PERSON_ID: &str = "48000";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(48000));
 
         let crew = response.crew;
@@ -381,7 +396,9 @@ mod tests {
         const PERSON_ID: &str = "240990";
         let (tmdb, _server, mock) = init(PERSON_ID).await;
 
-        let response = get(&tmdb, PERSON_ID).await.unwrap();
+        let response = get(&tmdb, PERSON_ID, &Localisation::default())
+            .await
+            .unwrap();
         assert_eq!(response.id, Some(240990));
 
         let cast = response.cast;
@@ -418,4 +435,36 @@ mod tests {
 
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_get_19498_localised() {
+        const PERSON_ID: &str = "19498";
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock =
+            mock_get_person_combined_credits_with_language(&mut server, PERSON_ID, "de-DE").await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let localisation = Localisation {
+            language: Some(Language::from_str("de-DE").unwrap()),
+            region: None,
+        };
+
+        let response = get(&tmdb, PERSON_ID, &localisation).await.unwrap();
+
+        let movie = response
+            .cast
+            .iter()
+            .find_map(|cast| match cast {
+                Cast::Movie(movie) if movie.id == 870028 => Some(movie),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(movie.title, "The Accountant 2");
+
+        mock.assert();
+    }
 }
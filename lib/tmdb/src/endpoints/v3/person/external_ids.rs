@@ -0,0 +1,60 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::external_ids::ExternalIds;
+use tracing::instrument;
+
+/// [GET: Person External IDs](https://developer.themoviedb.org/reference/person-external-ids)
+///
+/// Performs a get request on the `person/{person_id}/external_ids` endpoint.
+#[instrument(level = "trace", name = "person::external_ids::get", skip(tmdb))]
+pub async fn get(tmdb: &Tmdb, person_id: i32) -> Result<ExternalIds, RequestError> {
+    let path = format!("person/{person_id}/external_ids");
+
+    get_cached(tmdb, Endpoint::PersonExternalIds, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tmdb_test_utils::api::v3::person::external_ids::mock_get_person_external_ids;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    async fn external_ids(person_id: i32) -> ExternalIds {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_person_external_ids(&mut server, person_id).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb, person_id).await.unwrap();
+
+        mock.assert();
+
+        response
+    }
+
+    #[tokio::test]
+    async fn test_get_19498() {
+        let response = external_ids(19498).await;
+
+        assert_eq!(response.id, 19498);
+        assert_eq!(response.imdb_id, Some("nm1256532".to_string()));
+        assert_eq!(response.wikidata_id, Some("Q1701254".to_string()));
+        assert_eq!(response.instagram_id, Some("jonnybernthal".to_string()));
+        assert_eq!(response.twitter_id, Some("jonnybernthal".to_string()));
+        assert_eq!(response.facebook_id, None);
+        assert_eq!(response.tiktok_id, None);
+        assert_eq!(response.youtube_id, None);
+        assert_eq!(response.links().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_get_48000_none() {
+        let response = external_ids(48000).await;
+
+        assert_eq!(response.id, 48000);
+        assert_eq!(response.imdb_id, None);
+        assert!(response.links().is_empty());
+    }
+}
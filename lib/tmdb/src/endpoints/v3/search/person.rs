@@ -0,0 +1,73 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::paginated::Paginated;
+use crate::models::v3::person_search::PersonSearchResult;
+use tracing::instrument;
+use url::form_urlencoded::Serializer;
+
+/// [GET: Search Person](https://developer.themoviedb.org/reference/search-person)
+///
+/// Performs a get request on the `search/person` endpoint, `page` starting from 1.
+///
+/// ## NOTE
+/// The PersonSearchResult struct is not an exhaustive representation of the data provided by
+/// the api.
+#[instrument(level = "trace", name = "search::person::get", skip(tmdb))]
+pub async fn get(
+    tmdb: &Tmdb,
+    query: &str,
+    page: u32,
+) -> Result<Paginated<PersonSearchResult>, RequestError> {
+    let query_string = Serializer::new(String::new())
+        .append_pair("query", query)
+        .append_pair("page", page.to_string().as_str())
+        .finish();
+
+    let path = format!("search/person?{query_string}");
+
+    get_cached(tmdb, Endpoint::SearchPerson, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::v3::gender::Gender;
+    use tmdb_test_utils::api::v3::search::person::mock_get_search_person;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    #[tokio::test]
+    async fn test_get_bernthal() {
+        const QUERY: &str = "bernthal";
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_search_person(&mut server, QUERY, 1).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb, QUERY, 1).await.unwrap();
+
+        assert_eq!(response.page, 1);
+        assert_eq!(response.total_pages, 1);
+        assert_eq!(response.total_results, 2);
+        assert_eq!(response.next_page(), None);
+        assert_eq!(response.results.len(), 2);
+
+        let jon = &response.results[0];
+        assert_eq!(jon.id, 19498);
+        assert_eq!(jon.name, "Jon Bernthal");
+        assert_eq!(jon.gender, Gender::Male);
+        assert_eq!(jon.known_for_department, Some("Acting".to_string()));
+        assert_eq!(
+            jon.profile_path,
+            Some("/o0t6EVkJOrFAjESDilZUlf46IbQ.jpg".to_string())
+        );
+
+        let kate = &response.results[1];
+        assert_eq!(kate.id, 1772255);
+        assert_eq!(kate.profile_path, None);
+
+        mock.assert();
+    }
+}
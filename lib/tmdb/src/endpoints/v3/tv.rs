@@ -0,0 +1,118 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::tv_details::TvDetails;
+use tracing::instrument;
+
+pub mod season;
+
+/// [GET: TV Series Details](https://developer.themoviedb.org/reference/tv-series-details)
+///
+/// Performs a get request on the `tv/{series_id}` endpoint.
+///
+/// ## NOTE
+/// The TvDetails struct is not an exhaustive representation of the data provided by the api.
+#[instrument(level = "trace", name = "tv::get", skip(tmdb))]
+pub async fn get(tmdb: &Tmdb, series_id: i32) -> Result<TvDetails, RequestError> {
+    let path = format!("tv/{series_id}");
+
+    get_cached(tmdb, Endpoint::Tv, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::v3::genres::TvGenre;
+    use crate::models::v3::tv_status::TvStatus;
+    use chrono::NaiveDate;
+    use tmdb_test_utils::api::v3::tv::mock_get_tv_details;
+    use tmdb_test_utils::mockito::{Mock, ServerGuard};
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    async fn init(series_id: i32) -> (Tmdb, ServerGuard, Mock) {
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_tv_details(&mut server, series_id).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        (tmdb, server, mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_202555() {
+        const SERIES_ID: i32 = 202555;
+
+        let (tmdb, _server, mock) = init(SERIES_ID).await;
+        let response = get(&tmdb, SERIES_ID).await.unwrap();
+
+        assert_eq!(response.id, SERIES_ID);
+        assert_eq!(response.name, "Daredevil: Born Again");
+        assert_eq!(
+            response.first_air_date,
+            NaiveDate::parse_from_str("2025-03-04", "%Y-%m-%d").ok()
+        );
+        assert_eq!(
+            response.last_air_date,
+            NaiveDate::parse_from_str("2025-04-15", "%Y-%m-%d").ok()
+        );
+        assert_eq!(response.genres, vec![TvGenre::Drama, TvGenre::Crime]);
+        assert!(response.in_production);
+        assert_eq!(response.status, TvStatus::ReturningSeries);
+        assert_eq!(response.number_of_episodes, 18);
+        assert_eq!(response.number_of_seasons, 2);
+        assert_eq!(
+            response.tagline,
+            Some("The devil has come home.".to_string())
+        );
+
+        let last_episode = response.last_episode_to_air.unwrap();
+        assert_eq!(last_episode.name, "Straight to Hell");
+        assert_eq!(last_episode.season_number, 1);
+        assert_eq!(last_episode.episode_number, 9);
+        assert_eq!(last_episode.runtime, Some(51));
+
+        let next_episode = response.next_episode_to_air.unwrap();
+        assert_eq!(
+            next_episode.air_date,
+            NaiveDate::parse_from_str("2026-03-24", "%Y-%m-%d").ok()
+        );
+        assert_eq!(next_episode.season_number, 2);
+        assert_eq!(next_episode.episode_number, 1);
+        assert_eq!(next_episode.runtime, None);
+        assert_eq!(next_episode.overview, None);
+
+        assert_eq!(response.seasons.len(), 2);
+        assert_eq!(response.seasons[1].name, "Season 2");
+        assert_eq!(response.seasons[1].episode_count, 9);
+        assert_eq!(response.seasons[1].poster_path, None);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_67178() {
+        const SERIES_ID: i32 = 67178;
+
+        let (tmdb, _server, mock) = init(SERIES_ID).await;
+        let response = get(&tmdb, SERIES_ID).await.unwrap();
+
+        assert_eq!(response.name, "Marvel's The Punisher");
+        assert_eq!(
+            response.genres,
+            vec![TvGenre::ActionAndAdventure, TvGenre::Crime, TvGenre::Drama]
+        );
+        assert!(!response.in_production);
+        assert_eq!(response.status, TvStatus::Ended);
+        assert_eq!(response.homepage, None);
+        assert_eq!(response.tagline, None);
+        assert!(response.production_companies.is_empty());
+        assert!(response.next_episode_to_air.is_none());
+        assert_eq!(
+            response.last_episode_to_air.unwrap().air_date,
+            NaiveDate::parse_from_str("2019-01-18", "%Y-%m-%d").ok()
+        );
+
+        mock.assert();
+    }
+}
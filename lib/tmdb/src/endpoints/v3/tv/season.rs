@@ -0,0 +1,67 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::season::SeasonDetails;
+use tracing::instrument;
+
+pub mod episode;
+
+/// [GET: TV Season Details](https://developer.themoviedb.org/reference/tv-season-details)
+///
+/// Performs a get request on the `tv/{series_id}/season/{season_number}` endpoint.
+#[instrument(level = "trace", name = "tv::season::get", skip(tmdb))]
+pub async fn get(
+    tmdb: &Tmdb,
+    series_id: i32,
+    season_number: u32,
+) -> Result<SeasonDetails, RequestError> {
+    let path = format!("tv/{series_id}/season/{season_number}");
+
+    get_cached(tmdb, Endpoint::TvSeason, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use tmdb_test_utils::api::v3::tv::season::mock_get_tv_season_details;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    #[tokio::test]
+    async fn test_get_202555_season_1() {
+        const SERIES_ID: i32 = 202555;
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_tv_season_details(&mut server, SERIES_ID, 1).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb, SERIES_ID, 1).await.unwrap();
+
+        assert_eq!(response.id, 322637);
+        assert_eq!(response.name, "Season 1");
+        assert_eq!(response.season_number, 1);
+        assert_eq!(response.overview, None);
+        assert_eq!(response.episodes.len(), 9);
+
+        let air_dates = response
+            .episodes
+            .iter()
+            .map(|episode| episode.air_date)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            air_dates[..3],
+            [
+                NaiveDate::from_ymd_opt(2025, 3, 4),
+                NaiveDate::from_ymd_opt(2025, 3, 4),
+                NaiveDate::from_ymd_opt(2025, 3, 11),
+            ]
+        );
+        assert_eq!(air_dates[8], NaiveDate::from_ymd_opt(2025, 4, 15));
+        assert_eq!(response.episodes[8].name, "Straight to Hell");
+
+        mock.assert();
+    }
+}
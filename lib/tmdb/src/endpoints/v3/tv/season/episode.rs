@@ -0,0 +1,55 @@
+use crate::Tmdb;
+use crate::cache::Endpoint;
+use crate::endpoints::{RequestError, get_cached};
+use crate::models::v3::episode::Episode;
+use tracing::instrument;
+
+/// [GET: TV Episode Details](https://developer.themoviedb.org/reference/tv-episode-details)
+///
+/// Performs a get request on the `tv/{series_id}/season/{season_number}/episode/{episode_number}`
+/// endpoint.
+#[instrument(level = "trace", name = "tv::season::episode::get", skip(tmdb))]
+pub async fn get(
+    tmdb: &Tmdb,
+    series_id: i32,
+    season_number: u32,
+    episode_number: u32,
+) -> Result<Episode, RequestError> {
+    let path = format!("tv/{series_id}/season/{season_number}/episode/{episode_number}");
+
+    get_cached(tmdb, Endpoint::TvSeasonEpisode, path, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use tmdb_test_utils::api::v3::tv::season::episode::mock_get_tv_episode_details;
+    use tmdb_test_utils::start_mock_tmdb_api;
+
+    #[tokio::test]
+    async fn test_get_202555_season_1_episode_1() {
+        const SERIES_ID: i32 = 202555;
+
+        let mut server = start_mock_tmdb_api().await;
+        let mock = mock_get_tv_episode_details(&mut server, SERIES_ID, 1, 1).await;
+
+        let mut tmdb = Tmdb::default();
+        tmdb.override_api_url(server.url().as_str()).unwrap();
+
+        let response = get(&tmdb, SERIES_ID, 1, 1).await.unwrap();
+
+        assert_eq!(response.id, 5890611);
+        assert_eq!(response.name, "Heaven's Half Hour");
+        assert_eq!(response.air_date, NaiveDate::from_ymd_opt(2025, 3, 4));
+        assert_eq!(response.season_number, 1);
+        assert_eq!(response.episode_number, 1);
+        assert_eq!(response.runtime, Some(55));
+        assert_eq!(
+            response.still_path,
+            Some("/oBZ5SZY8xcmdq4K3wAqmjbSjNmL.jpg".to_string())
+        );
+
+        mock.assert();
+    }
+}
@@ -0,0 +1,122 @@
+use crate::IMAGE_URL;
+use crate::models::v3::configuration::ImagesConfiguration;
+use url::Url;
+
+/// The size available for every kind of image
+pub const ORIGINAL_SIZE: &str = "original";
+
+/// The kinds of images hosted by TMDB, each of which is available in different sizes
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ImageKind {
+    Backdrop,
+    Logo,
+    Poster,
+    Profile,
+    Still,
+}
+
+/// Builds the urls of images hosted by TMDB from their paths, e.g. `MovieCast::poster_path`
+///
+/// When built from an [`ImagesConfiguration`], sizes which are unavailable for the kind of image
+/// fall back to [`ORIGINAL_SIZE`]. Otherwise, the default base url is used and the requested size
+/// is assumed to be available.
+#[derive(Debug, Clone)]
+pub struct ImageUrlBuilder {
+    base_url: Url,
+    configuration: Option<ImagesConfiguration>,
+}
+
+impl ImageUrlBuilder {
+    /// The url of the image at `path` in the requested `size`, e.g. `w342`
+    pub fn build(&self, kind: ImageKind, size: &str, path: &str) -> Option<Url> {
+        let size = match self.available_sizes(kind) {
+            Some(sizes) if !sizes.iter().any(|available| available == size) => ORIGINAL_SIZE,
+            _ => size,
+        };
+
+        self.base_url
+            .join(format!("{size}/{}", path.trim_start_matches('/')).as_str())
+            .ok()
+    }
+
+    fn available_sizes(&self, kind: ImageKind) -> Option<&[String]> {
+        let configuration = self.configuration.as_ref()?;
+
+        Some(match kind {
+            ImageKind::Backdrop => configuration.backdrop_sizes.as_slice(),
+            ImageKind::Logo => configuration.logo_sizes.as_slice(),
+            ImageKind::Poster => configuration.poster_sizes.as_slice(),
+            ImageKind::Profile => configuration.profile_sizes.as_slice(),
+            ImageKind::Still => configuration.still_sizes.as_slice(),
+        })
+    }
+}
+
+impl Default for ImageUrlBuilder {
+    fn default() -> Self {
+        Self {
+            base_url: IMAGE_URL.clone(),
+            configuration: None,
+        }
+    }
+}
+
+impl From<ImagesConfiguration> for ImageUrlBuilder {
+    fn from(configuration: ImagesConfiguration) -> Self {
+        Self {
+            base_url: configuration.secure_base_url.clone(),
+            configuration: Some(configuration),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POSTER_PATH: &str = "/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg";
+
+    fn init_configuration() -> ImagesConfiguration {
+        ImagesConfiguration {
+            secure_base_url: Url::parse("https://images.example.com/t/p/").unwrap(),
+            backdrop_sizes: vec!["w300".to_string(), "original".to_string()],
+            logo_sizes: Vec::new(),
+            poster_sizes: vec!["w342".to_string(), "original".to_string()],
+            profile_sizes: Vec::new(),
+            still_sizes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        let builder = ImageUrlBuilder::default();
+
+        assert_eq!(
+            builder
+                .build(ImageKind::Poster, "w342", POSTER_PATH)
+                .unwrap()
+                .as_str(),
+            "https://image.tmdb.org/t/p/w342/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg"
+        );
+    }
+
+    #[test]
+    fn test_configuration() {
+        let builder = ImageUrlBuilder::from(init_configuration());
+
+        assert_eq!(
+            builder
+                .build(ImageKind::Poster, "w342", POSTER_PATH)
+                .unwrap()
+                .as_str(),
+            "https://images.example.com/t/p/w342/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg"
+        );
+        assert_eq!(
+            builder
+                .build(ImageKind::Backdrop, "w342", POSTER_PATH)
+                .unwrap()
+                .as_str(),
+            "https://images.example.com/t/p/original/ieYaJz2nzs4wcqpWaofagzGoGPi.jpg"
+        );
+    }
+}
@@ -1,34 +1,113 @@
 pub mod api_version;
+pub mod builder;
+pub mod cache;
 pub mod endpoints;
+pub mod image_url;
+pub mod localisation;
 pub mod models;
-
+pub mod retry;
+pub mod throttle;
+
+use crate::builder::TmdbBuilder;
+use crate::cache::memory::MemoryCache;
+use crate::cache::{CacheBackend, CacheTtl, InFlight};
+use crate::localisation::Localisation;
+use crate::retry::RetryPolicy;
+use crate::throttle::{Throttle, ThrottleConfig};
 use reqwest::Client;
 use secrecy::SecretString;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 use url::Url;
 
 static DEFAULT_API_URL: LazyLock<Url> =
     LazyLock::new(|| Url::parse("https://api.themoviedb.org/").expect("valid str, tested"));
-static SITE_URL: LazyLock<Url> =
+/// The TMDB website, as opposed to the api
+pub static SITE_URL: LazyLock<Url> =
     LazyLock::new(|| Url::parse("https://www.themoviedb.org/").expect("valid str, tested"));
+/// The base url of images hosted by TMDB, to which the size and image path are appended
+pub static IMAGE_URL: LazyLock<Url> =
+    LazyLock::new(|| Url::parse("https://image.tmdb.org/t/p/").expect("valid str, tested"));
 static IMDB_SITE_URL: LazyLock<Url> =
     LazyLock::new(|| Url::parse("https://www.imdb.com/").expect("valid str, tested"));
 
 pub struct Tmdb {
     token: SecretString,
     http_client: Client,
+    /// The timeout of `http_client`, should it be known
+    timeout: Option<Duration>,
     api_url: Url,
+    localisation: Localisation,
+    retry_policy: RetryPolicy,
+    throttle: Throttle,
+    cache: Arc<dyn CacheBackend>,
+    cache_ttl: CacheTtl,
+    in_flight: InFlight,
 }
 
 impl Tmdb {
+    /// Configures a client, including its http client, validating the configuration when built
+    pub fn builder(token: SecretString) -> TmdbBuilder {
+        TmdbBuilder::new(token)
+    }
+
     pub fn new(http_client: Client, token: SecretString) -> Self {
+        Self::with_throttle(http_client, token, ThrottleConfig::default())
+    }
+
+    /// Constructs a client whose requests, across all endpoints, are limited by `throttle`
+    pub fn with_throttle(
+        http_client: Client,
+        token: SecretString,
+        throttle: ThrottleConfig,
+    ) -> Self {
         Self {
             token,
             http_client,
+            timeout: None,
             api_url: DEFAULT_API_URL.clone(),
+            localisation: Localisation::default(),
+            retry_policy: RetryPolicy::default(),
+            throttle: Throttle::new(throttle),
+            cache: Arc::new(MemoryCache::default()),
+            cache_ttl: CacheTtl::default(),
+            in_flight: InFlight::default(),
         }
     }
 
+    pub fn throttle(&self) -> &ThrottleConfig {
+        self.throttle.config()
+    }
+
+    /// The cache of successful responses, which may be inspected and invalidated
+    pub fn cache(&self) -> &dyn CacheBackend {
+        self.cache.as_ref()
+    }
+
+    /// How long the responses of each endpoint are cached
+    pub fn cache_ttl(&self) -> &CacheTtl {
+        &self.cache_ttl
+    }
+
+    /// The default language and region of requests, used by endpoints which are not provided a
+    /// [`Localisation`] and for any part of a provided [`Localisation`] which is unset
+    pub fn localisation(&self) -> &Localisation {
+        &self.localisation
+    }
+
+    pub fn set_localisation(&mut self, localisation: Localisation) {
+        self.localisation = localisation;
+    }
+
+    /// How requests failing with a transient error, such as being rate limited, are retried
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
     pub fn override_api_url<U: TryInto<Url>>(&mut self, url: U) -> Result<(), U::Error> {
         let url = url.try_into()?;
         self.api_url = url;
@@ -59,6 +138,11 @@ mod tests {
         assert_eq!(SITE_URL.as_str(), "https://www.themoviedb.org/");
     }
 
+    #[test]
+    fn test_static_image_url() {
+        assert_eq!(IMAGE_URL.as_str(), "https://image.tmdb.org/t/p/");
+    }
+
     #[test]
     fn test_static_imdb_site_url() {
         assert_eq!(IMDB_SITE_URL.as_str(), "https://www.imdb.com/");
@@ -71,6 +155,22 @@ mod tests {
         assert_eq!(tmdb.token.expose_secret(), "NO_TOKEN_REQUIRED");
         assert_eq!(tmdb.http_client.type_id(), TypeId::of::<Client>());
         assert_eq!(tmdb.api_url.as_str(), DEFAULT_API_URL.as_str());
+        assert_eq!(tmdb.localisation, Localisation::default());
+        assert_eq!(tmdb.retry_policy, RetryPolicy::default());
+        assert_eq!(tmdb.throttle(), &ThrottleConfig::default());
+        assert!(tmdb.cache().keys().is_empty());
+        assert_eq!(tmdb.cache_ttl(), &CacheTtl::default());
+    }
+
+    #[test]
+    fn test_with_throttle() {
+        let tmdb = Tmdb::with_throttle(
+            Client::new(),
+            SecretString::from("NO_TOKEN_REQUIRED"),
+            ThrottleConfig::unlimited(),
+        );
+
+        assert_eq!(tmdb.throttle(), &ThrottleConfig::unlimited());
     }
 
     #[test]
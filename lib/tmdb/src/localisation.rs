@@ -0,0 +1,73 @@
+use crate::models::v3::language::Language;
+use crate::models::v3::region::Region;
+
+/// The language and region in which TMDB should respond, affecting the titles, overviews and
+/// biographies of localised endpoints. TMDB responds in `en-US` should neither be provided.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Localisation {
+    pub language: Option<Language>,
+    pub region: Option<Region>,
+}
+
+impl Localisation {
+    /// Uses the language and region of `defaults` for any which have not been provided
+    pub fn or(&self, defaults: &Localisation) -> Localisation {
+        Localisation {
+            language: self.language.clone().or_else(|| defaults.language.clone()),
+            region: self.region.clone().or_else(|| defaults.region.clone()),
+        }
+    }
+
+    /// The query parameters appended to a request
+    pub(crate) fn query_pairs(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("language", self.language.as_ref().map(Language::as_str)),
+            ("region", self.region.as_ref().map(Region::as_str)),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_or() {
+        let defaults = Localisation {
+            language: Some(Language::from_str("en-GB").unwrap()),
+            region: Some(Region::from_str("GB").unwrap()),
+        };
+
+        let localisation = Localisation {
+            language: Some(Language::from_str("de-DE").unwrap()),
+            region: None,
+        };
+
+        assert_eq!(
+            localisation.or(&defaults),
+            Localisation {
+                language: Some(Language::from_str("de-DE").unwrap()),
+                region: Some(Region::from_str("GB").unwrap()),
+            }
+        );
+        assert_eq!(Localisation::default().or(&defaults), defaults);
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        assert_eq!(Localisation::default().query_pairs().count(), 0);
+
+        let localisation = Localisation {
+            language: Some(Language::from_str("de-DE").unwrap()),
+            region: Some(Region::from_str("DE").unwrap()),
+        };
+
+        assert_eq!(
+            localisation.query_pairs().collect::<Vec<_>>(),
+            vec![("language", "de-DE"), ("region", "DE")]
+        );
+    }
+}
@@ -1,9 +1,26 @@
 pub mod cast;
+pub mod configuration;
 pub mod credit;
 pub mod crew;
+pub mod episode;
+pub mod external_ids;
+pub mod external_source;
+pub mod find_results;
 pub mod gender;
 pub mod genre_id;
 pub mod genres;
+pub mod language;
 pub mod media_type;
+pub mod movie_details;
+pub mod movie_status;
+pub mod paginated;
 pub mod person_details;
+pub mod person_search;
+pub mod production_company;
+pub mod region;
+pub mod release_dates;
+pub mod season;
+pub mod spoken_language;
 pub mod tmdb_error;
+pub mod tv_details;
+pub mod tv_status;
@@ -44,6 +44,10 @@ pub struct MovieCast {
     pub overview: Option<String>,
     pub original_language: String,
     pub credit_id: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub backdrop_path: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Hash, Clone)]
@@ -65,6 +69,10 @@ pub struct TvCast {
     pub overview: Option<String>,
     pub original_language: String,
     pub credit_id: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub backdrop_path: Option<String>,
 }
 
 pub(super) fn deserialize_movie_genre<'de, D>(deserializer: D) -> Result<Vec<MovieGenre>, D::Error>
@@ -158,6 +166,16 @@ impl IsCredit for MovieCast {
         self.credit_id.as_str()
     }
 
+    #[inline]
+    fn poster_path(&self) -> Option<&str> {
+        self.poster_path.as_deref()
+    }
+
+    #[inline]
+    fn backdrop_path(&self) -> Option<&str> {
+        self.backdrop_path.as_deref()
+    }
+
     #[inline]
     fn media_type(&self) -> MediaType {
         Self::MEDIA_TYPE
@@ -218,6 +236,16 @@ impl IsCredit for TvCast {
         self.credit_id.as_str()
     }
 
+    #[inline]
+    fn poster_path(&self) -> Option<&str> {
+        self.poster_path.as_deref()
+    }
+
+    #[inline]
+    fn backdrop_path(&self) -> Option<&str> {
+        self.backdrop_path.as_deref()
+    }
+
     #[inline]
     fn media_type(&self) -> MediaType {
         Self::MEDIA_TYPE
@@ -249,6 +277,8 @@ mod tests {
             ),
             original_language: "en".to_string(),
             credit_id: "example-credit-id".to_string(),
+            poster_path: Some("/lz8vNyXeidqqOdJW9ZjnDAMb5Vr.jpg".to_string()),
+            backdrop_path: Some("/fKPgObonYgPgCdu88yjV7Hpn7Dp.jpg".to_string()),
         }
     }
 
@@ -268,6 +298,8 @@ mod tests {
             ),
             original_language: "en".to_string(),
             credit_id: "example-credit-id".to_string(),
+            poster_path: Some("/c86aUAhACPyloPtL2CH4ZP5hO7V.jpg".to_string()),
+            backdrop_path: Some("/9czVEnJemvP6gcJlMEjUeuISL1c.jpg".to_string()),
         }
     }
 
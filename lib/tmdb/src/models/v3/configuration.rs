@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use url::Url;
+
+/// The configuration of the TMDB api
+///
+/// ## NOTE
+/// The change keys are not represented.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Configuration {
+    pub images: ImagesConfiguration,
+}
+
+/// The base urls of images hosted by TMDB, alongside the sizes in which each kind of image is
+/// available
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct ImagesConfiguration {
+    pub secure_base_url: Url,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub backdrop_sizes: Vec<String>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub logo_sizes: Vec<String>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub poster_sizes: Vec<String>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub profile_sizes: Vec<String>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub still_sizes: Vec<String>,
+}
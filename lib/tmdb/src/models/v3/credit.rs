@@ -42,6 +42,8 @@ pub trait IsCredit {
     fn original_language(&self) -> &str;
     fn overview(&self) -> Option<&String>;
     fn credit_id(&self) -> &str;
+    fn poster_path(&self) -> Option<&str>;
+    fn backdrop_path(&self) -> Option<&str>;
 
     // Other
     fn media_type(&self) -> MediaType;
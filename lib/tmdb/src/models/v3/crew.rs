@@ -54,6 +54,10 @@ pub struct MovieCrew {
     pub overview: Option<String>,
     pub original_language: String,
     pub credit_id: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub backdrop_path: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Hash, Clone)]
@@ -75,6 +79,10 @@ pub struct TvCrew {
     pub overview: Option<String>,
     pub original_language: String,
     pub credit_id: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub backdrop_path: Option<String>,
 }
 
 impl MovieCrew {
@@ -126,6 +134,16 @@ impl IsCredit for MovieCrew {
         self.credit_id.as_str()
     }
 
+    #[inline]
+    fn poster_path(&self) -> Option<&str> {
+        self.poster_path.as_deref()
+    }
+
+    #[inline]
+    fn backdrop_path(&self) -> Option<&str> {
+        self.backdrop_path.as_deref()
+    }
+
     #[inline]
     fn media_type(&self) -> MediaType {
         Self::MEDIA_TYPE
@@ -186,6 +204,16 @@ impl IsCredit for TvCrew {
         self.credit_id.as_str()
     }
 
+    #[inline]
+    fn poster_path(&self) -> Option<&str> {
+        self.poster_path.as_deref()
+    }
+
+    #[inline]
+    fn backdrop_path(&self) -> Option<&str> {
+        self.backdrop_path.as_deref()
+    }
+
     #[inline]
     fn media_type(&self) -> MediaType {
         Self::MEDIA_TYPE
@@ -218,6 +246,8 @@ mod tests {
             ),
             original_language: "en".to_string(),
             credit_id: "example-credit-id".to_string(),
+            poster_path: Some("/xRijtHPOflNKMagDmN9hWkDJyU8.jpg".to_string()),
+            backdrop_path: None,
         }
     }
 
@@ -238,6 +268,8 @@ mod tests {
             ),
             original_language: "en".to_string(),
             credit_id: "example-credit-id".to_string(),
+            poster_path: Some("/tw3tzfXaSpmUZIB8ZNqNEGzMBCy.jpg".to_string()),
+            backdrop_path: Some("/PqIW7PA74YBURz5slZkxc0K5Pl.jpg".to_string()),
         }
     }
 
@@ -0,0 +1,27 @@
+use crate::models::v3::movie_details::deserialize_runtime;
+use crate::models::v3::person_details::deserialize_date;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_utils::deserialize_potentially_empty_string;
+
+/// An episode of a tv series
+///
+/// ## NOTE
+/// The Episode struct is not an exhaustive representation of the data provided by the api, the
+/// crew and guest stars are omitted.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Episode {
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_date", default)]
+    pub air_date: Option<NaiveDate>,
+    pub episode_number: u32,
+    pub season_number: u32,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub overview: Option<String>,
+    /// Runtime in minutes
+    #[serde(deserialize_with = "deserialize_runtime", default)]
+    pub runtime: Option<u32>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub still_path: Option<String>,
+}
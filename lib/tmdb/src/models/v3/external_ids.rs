@@ -0,0 +1,138 @@
+use crate::IMDB_SITE_URL;
+use serde::Deserialize;
+use serde_utils::deserialize_potentially_empty_string;
+use std::fmt::{Display, Formatter};
+use url::Url;
+
+/// The ids of a person on other websites
+///
+/// ## NOTE
+/// The deprecated freebase and tvrage ids are not represented.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ExternalIds {
+    #[serde(default)]
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub imdb_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub wikidata_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub facebook_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub instagram_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub tiktok_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub twitter_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub youtube_id: Option<String>,
+}
+
+impl ExternalIds {
+    /// The url of each website on which the person has an id
+    pub fn links(&self) -> Vec<(ExternalSite, Url)> {
+        [
+            (ExternalSite::Imdb, &self.imdb_id),
+            (ExternalSite::Wikidata, &self.wikidata_id),
+            (ExternalSite::Instagram, &self.instagram_id),
+            (ExternalSite::TikTok, &self.tiktok_id),
+            (ExternalSite::Twitter, &self.twitter_id),
+            (ExternalSite::Facebook, &self.facebook_id),
+            (ExternalSite::YouTube, &self.youtube_id),
+        ]
+        .into_iter()
+        .filter_map(|(site, id)| Some((site, site.url(id.as_deref()?)?)))
+        .collect()
+    }
+}
+
+/// The websites for which TMDB holds the ids of people
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ExternalSite {
+    Imdb,
+    Wikidata,
+    Instagram,
+    TikTok,
+    Twitter,
+    Facebook,
+    YouTube,
+}
+
+impl ExternalSite {
+    /// The url of the profile with the provided id
+    pub fn url(&self, id: &str) -> Option<Url> {
+        match self {
+            ExternalSite::Imdb => IMDB_SITE_URL.join(format!("/name/{id}").as_str()).ok(),
+            ExternalSite::Wikidata => {
+                Url::parse(format!("https://www.wikidata.org/wiki/{id}").as_str()).ok()
+            }
+            ExternalSite::Instagram => {
+                Url::parse(format!("https://www.instagram.com/{id}").as_str()).ok()
+            }
+            ExternalSite::TikTok => {
+                Url::parse(format!("https://www.tiktok.com/@{id}").as_str()).ok()
+            }
+            ExternalSite::Twitter => Url::parse(format!("https://x.com/{id}").as_str()).ok(),
+            ExternalSite::Facebook => {
+                Url::parse(format!("https://www.facebook.com/{id}").as_str()).ok()
+            }
+            ExternalSite::YouTube => {
+                Url::parse(format!("https://www.youtube.com/@{id}").as_str()).ok()
+            }
+        }
+    }
+}
+
+impl Display for ExternalSite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ExternalSite::Imdb => "IMDb",
+            ExternalSite::Wikidata => "Wikidata",
+            ExternalSite::Instagram => "Instagram",
+            ExternalSite::TikTok => "TikTok",
+            ExternalSite::Twitter => "X (Twitter)",
+            ExternalSite::Facebook => "Facebook",
+            ExternalSite::YouTube => "YouTube",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links() {
+        let external_ids = ExternalIds {
+            id: 19498,
+            imdb_id: Some("nm1256532".to_string()),
+            tiktok_id: Some("jonnybernthal".to_string()),
+            ..ExternalIds::default()
+        };
+
+        let links = external_ids
+            .links()
+            .into_iter()
+            .map(|(site, url)| (site, url.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            links,
+            vec![
+                (
+                    ExternalSite::Imdb,
+                    "https://www.imdb.com/name/nm1256532".to_string()
+                ),
+                (
+                    ExternalSite::TikTok,
+                    "https://www.tiktok.com/@jonnybernthal".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links_none() {
+        assert!(ExternalIds::default().links().is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+/// The external sources by which the find endpoint can look up TMDB objects
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ExternalSource {
+    ImdbId,
+    FacebookId,
+    InstagramId,
+    TvdbId,
+    TiktokId,
+    TwitterId,
+    WikidataId,
+    YoutubeId,
+}
+
+impl ExternalSource {
+    /// The value of the `external_source` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExternalSource::ImdbId => "imdb_id",
+            ExternalSource::FacebookId => "facebook_id",
+            ExternalSource::InstagramId => "instagram_id",
+            ExternalSource::TvdbId => "tvdb_id",
+            ExternalSource::TiktokId => "tiktok_id",
+            ExternalSource::TwitterId => "twitter_id",
+            ExternalSource::WikidataId => "wikidata_id",
+            ExternalSource::YoutubeId => "youtube_id",
+        }
+    }
+}
+
+impl Display for ExternalSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,12 @@
+use crate::models::v3::person_search::PersonSearchResult;
+use serde::Deserialize;
+
+/// The TMDB objects matching an external id
+///
+/// ## NOTE
+/// Only people are currently represented, the movie, tv, season and episode results are ignored.
+#[derive(Debug, Deserialize, Clone)]
+pub struct FindResults {
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub person_results: Vec<PersonSearchResult>,
+}
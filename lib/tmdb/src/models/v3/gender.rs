@@ -1,7 +1,8 @@
 use serde::{Deserialize, Deserializer};
 
-#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Default)]
 pub enum Gender {
+    #[default]
     NotSpecified,
     Female,
     Male,
@@ -23,9 +24,3 @@ impl<'de> Deserialize<'de> for Gender {
         })
     }
 }
-
-impl Default for Gender {
-    fn default() -> Self {
-        Self::NotSpecified
-    }
-}
@@ -1,4 +1,5 @@
 use crate::models::v3::genre_id::GenreId;
+use serde::{Deserialize, Deserializer};
 use std::fmt::{Debug, Display, Formatter};
 use tmdb_macros::make_genre;
 
@@ -163,6 +164,23 @@ make_genre!(
     }"#
 );
 
+/// Deserialises genres provided as a list of objects with an id and name, as opposed to the list of
+/// genre ids provided within credits
+pub(super) fn deserialize_genres<'de, D, G>(deserializer: D) -> Result<Vec<G>, D::Error>
+where
+    D: Deserializer<'de>,
+    G: From<GenreId>,
+{
+    #[derive(Deserialize)]
+    struct NamedGenre {
+        id: GenreId,
+    }
+
+    let genres = Vec::<NamedGenre>::deserialize(deserializer)?;
+
+    Ok(genres.into_iter().map(|genre| G::from(genre.id)).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// An [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) language code, optionally followed by
+/// an [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) region code, e.g.
+/// `de` or `de-DE`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Language(String);
+
+#[derive(Debug, Error, Eq, PartialEq)]
+#[error("language must be an ISO 639-1 code optionally followed by a region, e.g. de or de-DE")]
+pub struct InvalidLanguage;
+
+impl Language {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+fn is_alpha_2(s: &str) -> bool {
+    s.len() == 2 && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+impl FromStr for Language {
+    type Err = InvalidLanguage;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        match s.split_once(['-', '_']) {
+            None if is_alpha_2(s) => Ok(Self(s.to_ascii_lowercase())),
+            Some((language, region)) if is_alpha_2(language) && is_alpha_2(region) => {
+                Ok(Self(format!(
+                    "{}-{}",
+                    language.to_ascii_lowercase(),
+                    region.to_ascii_uppercase()
+                )))
+            }
+            _ => Err(InvalidLanguage),
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Language::from_str(s.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalised() {
+        assert_eq!(Language::from_str("DE").unwrap().as_str(), "de");
+        assert_eq!(Language::from_str("de-de").unwrap().as_str(), "de-DE");
+        assert_eq!(Language::from_str("pt_br").unwrap().as_str(), "pt-BR");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Language::from_str("deu"), Err(InvalidLanguage));
+        assert_eq!(Language::from_str("de-DEU"), Err(InvalidLanguage));
+        assert_eq!(Language::from_str("d1"), Err(InvalidLanguage));
+        assert_eq!(Language::from_str(""), Err(InvalidLanguage));
+    }
+}
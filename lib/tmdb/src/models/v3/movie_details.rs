@@ -0,0 +1,129 @@
+use crate::models::v3::genres::{MovieGenre, deserialize_genres};
+use crate::models::v3::movie_status::MovieStatus;
+use crate::models::v3::person_details::deserialize_date;
+use crate::models::v3::production_company::ProductionCompany;
+use crate::models::v3::spoken_language::SpokenLanguage;
+use crate::{IMDB_SITE_URL, SITE_URL};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer};
+use serde_utils::deserialize_potentially_empty_string;
+use url::Url;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MovieDetails {
+    #[serde(default)]
+    pub adult: bool,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub backdrop_path: Option<String>,
+    /// Budget in US dollars, zero if unknown
+    #[serde(default)]
+    pub budget: u64,
+    #[serde(
+        deserialize_with = "deserialize_genres",
+        default = "serde_utils::vec_zero_size"
+    )]
+    pub genres: Vec<MovieGenre>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub homepage: Option<String>,
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub imdb_id: Option<String>,
+    pub original_language: String,
+    pub original_title: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub overview: Option<String>,
+    #[serde(default)]
+    pub popularity: f32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub production_companies: Vec<ProductionCompany>,
+    #[serde(deserialize_with = "deserialize_date", default)]
+    pub release_date: Option<NaiveDate>,
+    /// Revenue in US dollars, zero if unknown
+    #[serde(default)]
+    pub revenue: u64,
+    /// Runtime in minutes
+    #[serde(deserialize_with = "deserialize_runtime", default)]
+    pub runtime: Option<u32>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub spoken_languages: Vec<SpokenLanguage>,
+    pub status: MovieStatus,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub tagline: Option<String>,
+    pub title: String,
+}
+
+/// The api provides a runtime of zero should it be unknown
+pub(super) fn deserialize_runtime<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let runtime = Option::<u32>::deserialize(deserializer)?;
+
+    Ok(runtime.filter(|runtime| *runtime > 0))
+}
+
+impl MovieDetails {
+    pub fn tmdb_url(&self) -> Url {
+        SITE_URL
+            .join(format!("movie/{}", self.id).as_str())
+            .expect("url should always be valid")
+    }
+
+    pub fn imdb_url(&self) -> Option<Url> {
+        self.imdb_id.as_ref().map(|id| {
+            IMDB_SITE_URL
+                .join(format!("/title/{id}").as_str())
+                .expect("url should always be valid")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init() -> MovieDetails {
+        MovieDetails {
+            adult: false,
+            backdrop_path: None,
+            budget: 0,
+            genres: vec![MovieGenre::Crime],
+            homepage: None,
+            id: 870028,
+            imdb_id: Some("tt7068946".to_string()),
+            original_language: "en".to_string(),
+            original_title: "The Accountant²".to_string(),
+            overview: None,
+            popularity: 0.0,
+            poster_path: None,
+            production_companies: Vec::new(),
+            release_date: None,
+            revenue: 0,
+            runtime: Some(132),
+            spoken_languages: Vec::new(),
+            status: MovieStatus::Released,
+            tagline: None,
+            title: "The Accountant²".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tmdb_url() {
+        let details = init();
+        assert_eq!(
+            details.tmdb_url().as_str(),
+            "https://www.themoviedb.org/movie/870028"
+        );
+    }
+
+    #[test]
+    fn test_imdb_url() {
+        let details = init();
+        assert_eq!(
+            details.imdb_url().unwrap().as_str(),
+            "https://www.imdb.com/title/tt7068946"
+        );
+    }
+}
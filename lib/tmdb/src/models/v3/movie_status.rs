@@ -0,0 +1,91 @@
+use serde::{Deserialize, Deserializer};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The production status of a movie
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MovieStatus {
+    Rumored,
+    Planned,
+    InProduction,
+    PostProduction,
+    Released,
+    Canceled,
+    Unknown(String),
+}
+
+impl FromStr for MovieStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Ok(match s.to_lowercase().as_str() {
+            "rumored" => Self::Rumored,
+            "planned" => Self::Planned,
+            "in production" => Self::InProduction,
+            "post production" => Self::PostProduction,
+            "released" => Self::Released,
+            "canceled" => Self::Canceled,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Display for MovieStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MovieStatus::Rumored => f.write_str("Rumored"),
+            MovieStatus::Planned => f.write_str("Planned"),
+            MovieStatus::InProduction => f.write_str("In Production"),
+            MovieStatus::PostProduction => f.write_str("Post Production"),
+            MovieStatus::Released => f.write_str("Released"),
+            MovieStatus::Canceled => f.write_str("Canceled"),
+            MovieStatus::Unknown(s) => write!(f, "Unknown({s})"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MovieStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(MovieStatus::from_str(s.as_str()).expect("infallible"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_production() {
+        assert_eq!(
+            MovieStatus::from_str("In Production").unwrap(),
+            MovieStatus::InProduction
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(
+            MovieStatus::from_str("Lost").unwrap(),
+            MovieStatus::Unknown("Lost".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let status = MovieStatus::PostProduction;
+
+        assert_eq!(
+            MovieStatus::from_str(status.to_string().as_str()).unwrap(),
+            status
+        );
+    }
+}
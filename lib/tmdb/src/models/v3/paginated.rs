@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+/// A single page of results, as returned by the endpoints supporting pagination
+#[derive(Debug, Deserialize, Clone)]
+pub struct Paginated<T> {
+    pub page: u32,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub results: Vec<T>,
+    pub total_pages: u32,
+    pub total_results: u32,
+}
+
+impl<T> Paginated<T> {
+    /// The number of the next page, if any
+    pub fn next_page(&self) -> Option<u32> {
+        (self.page < self.total_pages).then(|| self.page + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_page() {
+        let paginated = Paginated::<()> {
+            page: 1,
+            results: Vec::new(),
+            total_pages: 2,
+            total_results: 30,
+        };
+        assert_eq!(paginated.next_page(), Some(2));
+
+        let paginated = Paginated {
+            page: 2,
+            ..paginated
+        };
+        assert_eq!(paginated.next_page(), None);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::SITE_URL;
+use crate::image_url::{ImageKind, ImageUrlBuilder};
+use crate::models::v3::gender::Gender;
+use serde::Deserialize;
+use serde_utils::deserialize_potentially_empty_string;
+use url::Url;
+
+/// A person as returned by the person search and find endpoints
+#[derive(Debug, Deserialize, Clone)]
+pub struct PersonSearchResult {
+    #[serde(default = "serde_utils::defaults::default_bool::<true>")]
+    pub adult: bool,
+    #[serde(default)]
+    pub gender: Gender,
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub known_for_department: Option<String>,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub original_name: Option<String>,
+    #[serde(default)]
+    pub popularity: f32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub profile_path: Option<String>,
+}
+
+impl PersonSearchResult {
+    pub fn tmdb_url(&self) -> Url {
+        SITE_URL
+            .join(format!("person/{}", self.id).as_str())
+            .expect("url should always be valid")
+    }
+
+    /// The url of the profile image, `size` being one of the profile sizes supported by TMDB, e.g.
+    /// `w185`
+    pub fn profile_url(&self, images: &ImageUrlBuilder, size: &str) -> Option<Url> {
+        self.profile_path
+            .as_deref()
+            .and_then(|path| images.build(ImageKind::Profile, size, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init() -> PersonSearchResult {
+        PersonSearchResult {
+            adult: false,
+            gender: Gender::Male,
+            id: 19498,
+            known_for_department: Some("Acting".to_string()),
+            name: "Jon Bernthal".to_string(),
+            original_name: Some("Jon Bernthal".to_string()),
+            popularity: 6.3126,
+            profile_path: Some("/o0t6EVkJOrFAjESDilZUlf46IbQ.jpg".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_tmdb_url() {
+        assert_eq!(
+            init().tmdb_url().as_str(),
+            "https://www.themoviedb.org/person/19498"
+        );
+    }
+
+    #[test]
+    fn test_profile_url() {
+        assert_eq!(
+            init()
+                .profile_url(&ImageUrlBuilder::default(), "w185")
+                .unwrap()
+                .as_str(),
+            "https://image.tmdb.org/t/p/w185/o0t6EVkJOrFAjESDilZUlf46IbQ.jpg"
+        );
+
+        let result = PersonSearchResult {
+            profile_path: None,
+            ..init()
+        };
+        assert_eq!(
+            result.profile_url(&ImageUrlBuilder::default(), "w185"),
+            None
+        );
+    }
+}
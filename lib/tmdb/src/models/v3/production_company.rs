@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde_utils::deserialize_potentially_empty_string;
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct ProductionCompany {
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub logo_path: Option<String>,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub origin_country: Option<String>,
+}
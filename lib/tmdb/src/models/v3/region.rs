@@ -0,0 +1,67 @@
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// An [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) region code, e.g.
+/// `GB`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Region(String);
+
+#[derive(Debug, Error, Eq, PartialEq)]
+#[error("region must be an ISO 3166-1 alpha-2 code, e.g. GB")]
+pub struct InvalidRegion;
+
+impl Region {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl FromStr for Region {
+    type Err = InvalidRegion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.len() != 2 || !s.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(InvalidRegion);
+        }
+
+        Ok(Self(s.to_ascii_uppercase()))
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Region {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Region::from_str(s.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uppercase() {
+        assert_eq!(Region::from_str("gb").unwrap().as_str(), "GB");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Region::from_str("GBR"), Err(InvalidRegion));
+        assert_eq!(Region::from_str("G1"), Err(InvalidRegion));
+        assert_eq!(Region::from_str(""), Err(InvalidRegion));
+    }
+}
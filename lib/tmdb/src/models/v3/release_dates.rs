@@ -0,0 +1,144 @@
+use crate::models::v3::region::Region;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer};
+use serde_utils::deserialize_potentially_empty_string;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The release dates of a movie within each region
+#[derive(Debug, Deserialize, Clone)]
+pub struct MovieReleaseDates {
+    pub id: i32,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub results: Vec<RegionReleaseDates>,
+}
+
+impl MovieReleaseDates {
+    /// The earliest date on which the movie is released as `release_type` within `region`
+    pub fn release_date(&self, region: &Region, release_type: ReleaseType) -> Option<NaiveDate> {
+        self.results
+            .iter()
+            .filter(|result| result.iso_3166_1.eq_ignore_ascii_case(region.as_str()))
+            .flat_map(|result| result.release_dates.iter())
+            .filter(|release| release.release_type == release_type)
+            .map(|release| release.release_date.date_naive())
+            .min()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegionReleaseDates {
+    /// The [ISO 3166-1](https://en.wikipedia.org/wiki/ISO_3166-1) code of the region
+    pub iso_3166_1: String,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub release_dates: Vec<ReleaseDate>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReleaseDate {
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub certification: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub iso_639_1: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub note: Option<String>,
+    pub release_date: DateTime<Utc>,
+    #[serde(rename = "type")]
+    pub release_type: ReleaseType,
+}
+
+/// [Release types](https://developer.themoviedb.org/reference/movie-release-dates) as defined
+/// by TMDB
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ReleaseType {
+    Premiere,
+    TheatricalLimited,
+    Theatrical,
+    Digital,
+    Physical,
+    Tv,
+    Unknown(u8),
+}
+
+impl From<u8> for ReleaseType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Premiere,
+            2 => Self::TheatricalLimited,
+            3 => Self::Theatrical,
+            4 => Self::Digital,
+            5 => Self::Physical,
+            6 => Self::Tv,
+            value => Self::Unknown(value),
+        }
+    }
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+#[error(
+    "release type must be one of premiere, theatrical_limited, theatrical, digital, physical or tv"
+)]
+pub struct InvalidReleaseType;
+
+/// Parses the snake case name of a release type, e.g. `theatrical_limited`
+impl FromStr for ReleaseType {
+    type Err = InvalidReleaseType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "premiere" => Ok(Self::Premiere),
+            "theatrical_limited" => Ok(Self::TheatricalLimited),
+            "theatrical" => Ok(Self::Theatrical),
+            "digital" => Ok(Self::Digital),
+            "physical" => Ok(Self::Physical),
+            "tv" => Ok(Self::Tv),
+            _ => Err(InvalidReleaseType),
+        }
+    }
+}
+
+impl Display for ReleaseType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseType::Premiere => f.write_str("Premiere"),
+            ReleaseType::TheatricalLimited => f.write_str("Theatrical (limited)"),
+            ReleaseType::Theatrical => f.write_str("Theatrical"),
+            ReleaseType::Digital => f.write_str("Digital"),
+            ReleaseType::Physical => f.write_str("Physical"),
+            ReleaseType::Tv => f.write_str("TV"),
+            ReleaseType::Unknown(value) => write!(f, "Unknown({value})"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ReleaseType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(ReleaseType::from(u8::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_u8() {
+        assert_eq!(ReleaseType::from(4), ReleaseType::Digital);
+        assert_eq!(ReleaseType::from(9), ReleaseType::Unknown(9));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            ReleaseType::from_str("theatrical_limited"),
+            Ok(ReleaseType::TheatricalLimited)
+        );
+        assert_eq!(ReleaseType::from_str("Digital"), Ok(ReleaseType::Digital));
+        assert_eq!(ReleaseType::from_str("cinema"), Err(InvalidReleaseType));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::models::v3::episode::Episode;
+use crate::models::v3::person_details::deserialize_date;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_utils::deserialize_potentially_empty_string;
+
+/// A season of a tv series, as listed within [`TvDetails`](crate::models::v3::tv_details::TvDetails)
+#[derive(Debug, Deserialize, Clone)]
+pub struct Season {
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_date", default)]
+    pub air_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub episode_count: u32,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub overview: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    pub season_number: u32,
+}
+
+/// A season of a tv series, including its episodes
+#[derive(Debug, Deserialize, Clone)]
+pub struct SeasonDetails {
+    pub id: i32,
+    #[serde(deserialize_with = "deserialize_date", default)]
+    pub air_date: Option<NaiveDate>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub episodes: Vec<Episode>,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub overview: Option<String>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    pub season_number: u32,
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct SpokenLanguage {
+    /// The name of the language in English
+    pub english_name: String,
+    /// The [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) code of the language
+    pub iso_639_1: String,
+    /// The name of the language in the language itself
+    pub name: String,
+}
@@ -92,6 +92,18 @@ impl Display for TmdbError {
 impl Error for TmdbError {}
 
 impl TmdbError {
+    /// Whether the error is transient, such that the same request may succeed if retried
+    pub const fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited
+                | Self::TimedOut
+                | Self::ServiceOffline
+                | Self::NoBackendConnection
+                | Self::Maintenance
+        )
+    }
+
     pub(crate) async fn try_from_response(response: Response) -> Result<Self, UnknownTmdbError> {
         let status = response.status();
 
@@ -264,6 +276,14 @@ mod tests {
         assert_eq!(error, Err(UnknownTmdbError::UnknownStatus(status_code)));
     }
 
+    #[test]
+    fn test_is_retryable() {
+        assert!(TmdbError::RateLimited.is_retryable());
+        assert!(TmdbError::ServiceOffline.is_retryable());
+        assert!(!TmdbError::InvalidId.is_retryable());
+        assert!(!TmdbError::InvalidApiKey.is_retryable());
+    }
+
     #[test]
     fn test_message() {
         let error = TmdbError::RateLimited;
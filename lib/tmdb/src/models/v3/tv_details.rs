@@ -0,0 +1,110 @@
+use crate::SITE_URL;
+use crate::models::v3::episode::Episode;
+use crate::models::v3::genres::{TvGenre, deserialize_genres};
+use crate::models::v3::person_details::deserialize_date;
+use crate::models::v3::production_company::ProductionCompany;
+use crate::models::v3::season::Season;
+use crate::models::v3::spoken_language::SpokenLanguage;
+use crate::models::v3::tv_status::TvStatus;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_utils::deserialize_potentially_empty_string;
+use url::Url;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TvDetails {
+    #[serde(default)]
+    pub adult: bool,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub backdrop_path: Option<String>,
+    #[serde(deserialize_with = "deserialize_date", default)]
+    pub first_air_date: Option<NaiveDate>,
+    #[serde(
+        deserialize_with = "deserialize_genres",
+        default = "serde_utils::vec_zero_size"
+    )]
+    pub genres: Vec<TvGenre>,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub homepage: Option<String>,
+    pub id: i32,
+    #[serde(default)]
+    pub in_production: bool,
+    #[serde(deserialize_with = "deserialize_date", default)]
+    pub last_air_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub last_episode_to_air: Option<Episode>,
+    pub name: String,
+    #[serde(default)]
+    pub next_episode_to_air: Option<Episode>,
+    #[serde(default)]
+    pub number_of_episodes: u32,
+    #[serde(default)]
+    pub number_of_seasons: u32,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub origin_country: Vec<String>,
+    pub original_language: String,
+    pub original_name: String,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub overview: Option<String>,
+    #[serde(default)]
+    pub popularity: f32,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub poster_path: Option<String>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub production_companies: Vec<ProductionCompany>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub seasons: Vec<Season>,
+    #[serde(default = "serde_utils::vec_zero_size")]
+    pub spoken_languages: Vec<SpokenLanguage>,
+    pub status: TvStatus,
+    #[serde(deserialize_with = "deserialize_potentially_empty_string", default)]
+    pub tagline: Option<String>,
+}
+
+impl TvDetails {
+    pub fn tmdb_url(&self) -> Url {
+        SITE_URL
+            .join(format!("tv/{}", self.id).as_str())
+            .expect("url should always be valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tmdb_url() {
+        let details = TvDetails {
+            adult: false,
+            backdrop_path: None,
+            first_air_date: None,
+            genres: Vec::new(),
+            homepage: None,
+            id: 202555,
+            in_production: true,
+            last_air_date: None,
+            last_episode_to_air: None,
+            name: "Daredevil: Born Again".to_string(),
+            next_episode_to_air: None,
+            number_of_episodes: 0,
+            number_of_seasons: 0,
+            origin_country: Vec::new(),
+            original_language: "en".to_string(),
+            original_name: "Daredevil: Born Again".to_string(),
+            overview: None,
+            popularity: 0.0,
+            poster_path: None,
+            production_companies: Vec::new(),
+            seasons: Vec::new(),
+            spoken_languages: Vec::new(),
+            status: TvStatus::ReturningSeries,
+            tagline: None,
+        };
+
+        assert_eq!(
+            details.tmdb_url().as_str(),
+            "https://www.themoviedb.org/tv/202555"
+        );
+    }
+}
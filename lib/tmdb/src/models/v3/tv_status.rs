@@ -0,0 +1,91 @@
+use serde::{Deserialize, Deserializer};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The production status of a tv series
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum TvStatus {
+    ReturningSeries,
+    Planned,
+    InProduction,
+    Pilot,
+    Ended,
+    Canceled,
+    Unknown(String),
+}
+
+impl FromStr for TvStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Ok(match s.to_lowercase().as_str() {
+            "returning series" => Self::ReturningSeries,
+            "planned" => Self::Planned,
+            "in production" => Self::InProduction,
+            "pilot" => Self::Pilot,
+            "ended" => Self::Ended,
+            "canceled" => Self::Canceled,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Display for TvStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TvStatus::ReturningSeries => f.write_str("Returning Series"),
+            TvStatus::Planned => f.write_str("Planned"),
+            TvStatus::InProduction => f.write_str("In Production"),
+            TvStatus::Pilot => f.write_str("Pilot"),
+            TvStatus::Ended => f.write_str("Ended"),
+            TvStatus::Canceled => f.write_str("Canceled"),
+            TvStatus::Unknown(s) => write!(f, "Unknown({s})"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TvStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(TvStatus::from_str(s.as_str()).expect("infallible"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_returning_series() {
+        assert_eq!(
+            TvStatus::from_str("Returning Series").unwrap(),
+            TvStatus::ReturningSeries
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(
+            TvStatus::from_str("Lost").unwrap(),
+            TvStatus::Unknown("Lost".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let status = TvStatus::ReturningSeries;
+
+        assert_eq!(
+            TvStatus::from_str(status.to_string().as_str()).unwrap(),
+            status
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use http::header::RETRY_AFTER;
+use std::time::Duration;
+
+/// How requests which fail with a transient [`TmdbError`](crate::models::v3::tmdb_error::TmdbError),
+/// such as being rate limited, or which fail to connect or time out, are retried. Only idempotent
+/// requests are retried.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// Number of retries after the initial attempt
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled for each subsequent retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the backoff. A `Retry-After` exceeding this is not waited for and the
+    /// error is returned instead.
+    pub max_backoff: Duration,
+    /// Upper bound of the time spent on a request from its first attempt, including retries.
+    /// Each attempt is timed out once this elapses, and retries which would start after it are
+    /// not attempted.
+    pub max_elapsed: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_elapsed: Duration::from_secs(20),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// The duration to wait before retry number `retry`, starting at 0, of a request whose first
+    /// attempt was `elapsed` ago, or [`None`] should the request not be retried.
+    ///
+    /// `retry_after` is honoured when provided, otherwise the exponential backoff is randomised
+    /// between half and all of its value to avoid concurrent requests retrying in lockstep.
+    pub(crate) fn backoff(
+        &self,
+        retry: u32,
+        retry_after: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+
+        let backoff = match retry_after {
+            Some(retry_after) => (retry_after <= self.max_backoff).then_some(retry_after)?,
+            None => self
+                .initial_backoff
+                .saturating_mul(2_u32.saturating_pow(retry))
+                .min(self.max_backoff)
+                .mul_f64(0.5 + fastrand::f64() / 2.0),
+        };
+
+        (elapsed.saturating_add(backoff) < self.max_elapsed).then_some(backoff)
+    }
+}
+
+/// Parses the `Retry-After` header, either a number of seconds or an HTTP date
+pub(crate) fn retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+
+    Some(
+        date.signed_duration_since(now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 4,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(3),
+            max_elapsed: Duration::from_secs(20),
+        };
+
+        for (retry, expected) in [(0, 1), (1, 2), (2, 3), (3, 3)] {
+            let expected = Duration::from_secs(expected);
+            let backoff = policy.backoff(retry, None, Duration::ZERO).unwrap();
+
+            assert!(backoff >= expected / 2 && backoff <= expected, "{retry}");
+        }
+
+        assert_eq!(policy.backoff(4, None, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_backoff_retry_after() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.backoff(0, Some(Duration::from_secs(2)), Duration::ZERO),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.backoff(0, Some(Duration::from_secs(31)), Duration::ZERO),
+            None
+        );
+        assert_eq!(
+            policy.backoff(3, Some(Duration::from_secs(2)), Duration::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_backoff_max_elapsed() {
+        let policy = RetryPolicy::default();
+        let retry_after = Some(Duration::from_secs(5));
+
+        assert_eq!(
+            policy.backoff(0, retry_after, Duration::from_secs(14)),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.backoff(0, retry_after, Duration::from_secs(15)),
+            None
+        );
+        assert_eq!(policy.backoff(0, None, Duration::from_secs(20)), None);
+    }
+
+    #[test]
+    fn test_disabled() {
+        assert_eq!(
+            RetryPolicy::disabled().backoff(0, None, Duration::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 May 2025 18:20:00 GMT")
+            .unwrap()
+            .to_utc();
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(retry_after(&headers, now), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("5"));
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(5)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 May 2025 18:20:10 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(10)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 May 2025 18:19:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers, now), None);
+    }
+}
@@ -0,0 +1,145 @@
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+/// Limits on the requests made by a [`Tmdb`](crate::Tmdb) client, shared by all endpoints, such
+/// that bursts of requests are queued rather than rejected by TMDB as
+/// [rate limited](https://developer.themoviedb.org/docs/rate-limiting)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ThrottleConfig {
+    /// Maximum number of requests in flight at once
+    pub max_concurrent: Option<NonZeroUsize>,
+    /// Maximum number of requests started per second, evenly spaced
+    pub requests_per_second: Option<NonZeroU32>,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: NonZeroUsize::new(20),
+            requests_per_second: NonZeroU32::new(40),
+        }
+    }
+}
+
+impl ThrottleConfig {
+    /// No limits, requests are made as soon as they are requested
+    pub fn unlimited() -> Self {
+        Self {
+            max_concurrent: None,
+            requests_per_second: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    config: ThrottleConfig,
+    semaphore: Option<Semaphore>,
+    /// The earliest instant at which the next request may start
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl Throttle {
+    pub(crate) fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            semaphore: config
+                .max_concurrent
+                .map(|max_concurrent| Semaphore::new(max_concurrent.get())),
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn config(&self) -> &ThrottleConfig {
+        &self.config
+    }
+
+    /// Waits until a request may be made, which is permitted until the returned permit is
+    /// dropped
+    pub(crate) async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        let permit = match &self.semaphore {
+            Some(semaphore) => Some(semaphore.acquire().await.expect("never closed")),
+            None => None,
+        };
+
+        if let Some(delay) = self.reserve(Instant::now()) {
+            tokio::time::sleep(delay).await;
+        }
+
+        permit
+    }
+
+    /// Reserves the next request slot, returning the delay until it starts should it not be `now`
+    fn reserve(&self, now: Instant) -> Option<Duration> {
+        let requests_per_second = self.config.requests_per_second?;
+        let interval = Duration::from_secs(1) / requests_per_second.get();
+
+        let mut next_slot = self
+            .next_slot
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let slot = next_slot.map_or(now, |next_slot| next_slot.max(now));
+        *next_slot = Some(slot + interval);
+
+        (slot > now).then(|| slot - now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_reserve() {
+        let throttle = Throttle::new(ThrottleConfig {
+            max_concurrent: None,
+            requests_per_second: NonZeroU32::new(4),
+        });
+        let now = Instant::now();
+
+        assert_eq!(throttle.reserve(now), None);
+        assert_eq!(throttle.reserve(now), Some(Duration::from_millis(250)));
+        assert_eq!(throttle.reserve(now), Some(Duration::from_millis(500)));
+
+        let later = now + Duration::from_secs(2);
+        assert_eq!(throttle.reserve(later), None);
+        assert_eq!(throttle.reserve(later), Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn test_reserve_unlimited() {
+        let throttle = Throttle::new(ThrottleConfig::unlimited());
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert_eq!(throttle.reserve(now), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acquire_max_concurrent() {
+        let throttle = Arc::new(Throttle::new(ThrottleConfig {
+            max_concurrent: NonZeroUsize::new(1),
+            requests_per_second: None,
+        }));
+
+        let permit = throttle.acquire().await;
+        assert!(permit.is_some());
+
+        let waiting = tokio::spawn({
+            let throttle = Arc::clone(&throttle);
+            async move { throttle.acquire().await.is_some() }
+        });
+
+        tokio::task::yield_now().await;
+        assert!(!waiting.is_finished());
+
+        drop(permit);
+        assert!(waiting.await.unwrap());
+    }
+}
@@ -0,0 +1,129 @@
+use secrecy::SecretString;
+use std::fs::remove_dir_all;
+use std::time::Duration;
+use tmdb::Tmdb;
+use tmdb::builder::TmdbBuilder;
+use tmdb::cache::disk::DiskCache;
+use tmdb::cache::none::NoCache;
+use tmdb::cache::{CacheKey, CacheTtl, Endpoint};
+use tmdb::endpoints::v3::{configuration, person};
+use tmdb::localisation::Localisation;
+use tmdb::throttle::ThrottleConfig;
+use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+use tmdb_test_utils::api::v3::person::mock_get_person_details;
+use tmdb_test_utils::start_mock_tmdb_api;
+
+const PERSON_ID: i32 = 19498;
+
+fn builder(api_url: String) -> TmdbBuilder {
+    Tmdb::builder(SecretString::from("THIS_IS_A_TEST"))
+        .api_url(api_url)
+        .throttle(ThrottleConfig::unlimited())
+}
+
+#[tokio::test]
+async fn test_no_cache() {
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_person_details(&mut server, PERSON_ID)
+        .await
+        .expect(2);
+
+    let tmdb = builder(server.url()).cache(NoCache).build().unwrap();
+
+    let _a = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+
+    mock.assert();
+    assert!(tmdb.cache().keys().is_empty());
+}
+
+#[tokio::test]
+async fn test_zero_ttl() {
+    let mut server = start_mock_tmdb_api().await;
+    let person_mock = mock_get_person_details(&mut server, PERSON_ID)
+        .await
+        .expect(2);
+    let configuration_mock = mock_get_configuration(&mut server).await.expect(1);
+
+    let tmdb = builder(server.url())
+        .cache_ttl(CacheTtl::default().with(Endpoint::Person, Duration::ZERO))
+        .build()
+        .unwrap();
+
+    let _a = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _c = configuration::get(&tmdb).await;
+    let _d = configuration::get(&tmdb).await;
+
+    person_mock.assert();
+    configuration_mock.assert();
+    assert_eq!(
+        tmdb.cache().keys(),
+        vec![CacheKey::new(Endpoint::Configuration, "/3/configuration")]
+    );
+}
+
+#[tokio::test]
+async fn test_invalidate() {
+    let mut server = start_mock_tmdb_api().await;
+    let person_mock = mock_get_person_details(&mut server, PERSON_ID)
+        .await
+        .expect(2);
+    let configuration_mock = mock_get_configuration(&mut server).await.expect(1);
+
+    let tmdb = builder(server.url()).build().unwrap();
+
+    let _a = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = configuration::get(&tmdb).await;
+
+    assert_eq!(
+        tmdb.cache().keys(),
+        vec![
+            CacheKey::new(Endpoint::Person, format!("/3/person/{PERSON_ID}")),
+            CacheKey::new(Endpoint::Configuration, "/3/configuration"),
+        ]
+    );
+
+    tmdb.cache().invalidate(Endpoint::Person);
+
+    let _c = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _d = configuration::get(&tmdb).await;
+
+    person_mock.assert();
+    configuration_mock.assert();
+}
+
+#[tokio::test]
+async fn test_disk_cache() {
+    let directory =
+        std::env::temp_dir().join(format!("tmdb-cache-integration-{}", std::process::id()));
+    let _ = remove_dir_all(directory.as_path());
+
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_person_details(&mut server, PERSON_ID)
+        .await
+        .expect(1);
+
+    let tmdb = builder(server.url())
+        .cache(DiskCache::new(directory.as_path()).unwrap())
+        .build()
+        .unwrap();
+    let a = person::get(&tmdb, PERSON_ID, &Localisation::default())
+        .await
+        .unwrap();
+
+    // a new client sharing the directory, as would be the case following a restart
+    let tmdb = builder(server.url())
+        .cache(DiskCache::new(directory.as_path()).unwrap())
+        .build()
+        .unwrap();
+    let b = person::get(&tmdb, PERSON_ID, &Localisation::default())
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(a.id, b.id);
+    assert_eq!(a.name, b.name);
+
+    remove_dir_all(directory).unwrap();
+}
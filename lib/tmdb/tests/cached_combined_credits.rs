@@ -2,6 +2,7 @@ use http::{Method, StatusCode};
 use tmdb::Tmdb;
 use tmdb::api_version::ApiVersion;
 use tmdb::endpoints::v3::person::combined_credits;
+use tmdb::localisation::Localisation;
 use tmdb_test_utils::api::misc::status_codes::mock_status_code_at_path;
 use tmdb_test_utils::api::v3::person::combined_credits::mock_get_person_combined_credits;
 use tmdb_test_utils::start_mock_tmdb_api;
@@ -18,8 +19,8 @@ async fn test_combined_credits_cache() {
     let mut tmdb = Tmdb::default();
     tmdb.override_api_url(server.url().as_str()).unwrap();
 
-    let _a = combined_credits::get(&tmdb, PERSON_ID).await;
-    let _b = combined_credits::get(&tmdb, PERSON_ID).await;
+    let _a = combined_credits::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = combined_credits::get(&tmdb, PERSON_ID, &Localisation::default()).await;
 
     mock.assert()
 }
@@ -47,8 +48,8 @@ async fn test_combined_credits_cache_err() {
     let mut tmdb = Tmdb::default();
     tmdb.override_api_url(server.url().as_str()).unwrap();
 
-    let _a = combined_credits::get(&tmdb, PERSON_ID).await;
-    let _b = combined_credits::get(&tmdb, PERSON_ID).await;
+    let _a = combined_credits::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = combined_credits::get(&tmdb, PERSON_ID, &Localisation::default()).await;
 
     mock.assert()
 }
@@ -0,0 +1,18 @@
+use tmdb::Tmdb;
+use tmdb::endpoints::v3::configuration;
+use tmdb_test_utils::api::v3::configuration::mock_get_configuration;
+use tmdb_test_utils::start_mock_tmdb_api;
+
+#[tokio::test]
+async fn test_configuration_cache() {
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_configuration(&mut server).await.expect(1);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = configuration::get(&tmdb).await;
+    let _b = configuration::get(&tmdb).await;
+
+    mock.assert()
+}
@@ -0,0 +1,55 @@
+use http::{Method, StatusCode};
+use tmdb::Tmdb;
+use tmdb::api_version::ApiVersion;
+use tmdb::endpoints::v3::find;
+use tmdb::models::v3::external_source::ExternalSource;
+use tmdb_test_utils::api::misc::status_codes::mock_status_code_at_path;
+use tmdb_test_utils::api::v3::find::mock_get_find;
+use tmdb_test_utils::start_mock_tmdb_api;
+
+#[tokio::test]
+async fn test_find_cache() {
+    const IMDB_ID: &str = "nm1256532";
+
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_find(&mut server, IMDB_ID, "imdb_id")
+        .await
+        .expect(1);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = find::get(&tmdb, IMDB_ID, ExternalSource::ImdbId).await;
+    let _b = find::get(&tmdb, IMDB_ID, ExternalSource::ImdbId).await;
+
+    mock.assert()
+}
+
+#[tokio::test]
+async fn test_find_cache_err() {
+    const IMDB_ID: &str = "nm-1";
+
+    let mut server = start_mock_tmdb_api().await;
+
+    let path = format!(
+        "/{}find/{IMDB_ID}?external_source=imdb_id",
+        ApiVersion::V3.base_path()
+    );
+
+    let mock = mock_status_code_at_path(
+        &mut server,
+        path.as_str(),
+        Method::GET,
+        StatusCode::NOT_FOUND,
+    )
+    .await
+    .expect(2);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = find::get(&tmdb, IMDB_ID, ExternalSource::ImdbId).await;
+    let _b = find::get(&tmdb, IMDB_ID, ExternalSource::ImdbId).await;
+
+    mock.assert()
+}
@@ -0,0 +1,51 @@
+use http::{Method, StatusCode};
+use tmdb::Tmdb;
+use tmdb::api_version::ApiVersion;
+use tmdb::endpoints::v3::movie;
+use tmdb_test_utils::api::misc::status_codes::mock_status_code_at_path;
+use tmdb_test_utils::api::v3::movie::mock_get_movie_details;
+use tmdb_test_utils::start_mock_tmdb_api;
+
+#[tokio::test]
+async fn test_movie_cache() {
+    const MOVIE_ID: i32 = 870028;
+
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_movie_details(&mut server, MOVIE_ID)
+        .await
+        .expect(1);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = movie::get(&tmdb, MOVIE_ID).await;
+    let _b = movie::get(&tmdb, MOVIE_ID).await;
+
+    mock.assert()
+}
+
+#[tokio::test]
+async fn test_movie_cache_err() {
+    const MOVIE_ID: i32 = -1;
+
+    let mut server = start_mock_tmdb_api().await;
+
+    let path = format!("/{}movie/{MOVIE_ID}", ApiVersion::V3.base_path());
+
+    let mock = mock_status_code_at_path(
+        &mut server,
+        path.as_str(),
+        Method::GET,
+        StatusCode::NOT_FOUND,
+    )
+    .await
+    .expect(2);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = movie::get(&tmdb, MOVIE_ID).await;
+    let _b = movie::get(&tmdb, MOVIE_ID).await;
+
+    mock.assert()
+}
@@ -1,9 +1,14 @@
 use http::{Method, StatusCode};
+use std::str::FromStr;
 use tmdb::Tmdb;
 use tmdb::api_version::ApiVersion;
 use tmdb::endpoints::v3::person;
+use tmdb::localisation::Localisation;
+use tmdb::models::v3::language::Language;
 use tmdb_test_utils::api::misc::status_codes::mock_status_code_at_path;
-use tmdb_test_utils::api::v3::person::mock_get_person_details;
+use tmdb_test_utils::api::v3::person::{
+    mock_get_person_details, mock_get_person_details_with_language,
+};
 use tmdb_test_utils::start_mock_tmdb_api;
 
 #[tokio::test]
@@ -18,8 +23,8 @@ async fn test_person_cache() {
     let mut tmdb = Tmdb::default();
     tmdb.override_api_url(server.url().as_str()).unwrap();
 
-    let _a = person::get(&tmdb, PERSON_ID).await;
-    let _b = person::get(&tmdb, PERSON_ID).await;
+    let _a = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
 
     mock.assert()
 }
@@ -44,8 +49,37 @@ async fn test_person_cache_err() {
     let mut tmdb = Tmdb::default();
     tmdb.override_api_url(server.url().as_str()).unwrap();
 
-    let _a = person::get(&tmdb, PERSON_ID).await;
-    let _b = person::get(&tmdb, PERSON_ID).await;
+    let _a = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
 
     mock.assert()
 }
+
+#[tokio::test]
+async fn test_person_cache_localised() {
+    const PERSON_ID: i32 = 956;
+
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_person_details(&mut server, PERSON_ID)
+        .await
+        .expect(1);
+    let localised_mock = mock_get_person_details_with_language(&mut server, PERSON_ID, "de-DE")
+        .await
+        .expect(1);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let localisation = Localisation {
+        language: Some(Language::from_str("de-DE").unwrap()),
+        region: None,
+    };
+
+    let _a = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _b = person::get(&tmdb, PERSON_ID, &localisation).await;
+    let _c = person::get(&tmdb, PERSON_ID, &Localisation::default()).await;
+    let _d = person::get(&tmdb, PERSON_ID, &localisation).await;
+
+    mock.assert();
+    localised_mock.assert()
+}
@@ -0,0 +1,49 @@
+use http::{Method, StatusCode};
+use tmdb::Tmdb;
+use tmdb::api_version::ApiVersion;
+use tmdb::endpoints::v3::tv;
+use tmdb_test_utils::api::misc::status_codes::mock_status_code_at_path;
+use tmdb_test_utils::api::v3::tv::mock_get_tv_details;
+use tmdb_test_utils::start_mock_tmdb_api;
+
+#[tokio::test]
+async fn test_tv_cache() {
+    const SERIES_ID: i32 = 202555;
+
+    let mut server = start_mock_tmdb_api().await;
+    let mock = mock_get_tv_details(&mut server, SERIES_ID).await.expect(1);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = tv::get(&tmdb, SERIES_ID).await;
+    let _b = tv::get(&tmdb, SERIES_ID).await;
+
+    mock.assert()
+}
+
+#[tokio::test]
+async fn test_tv_cache_err() {
+    const SERIES_ID: i32 = -1;
+
+    let mut server = start_mock_tmdb_api().await;
+
+    let path = format!("/{}tv/{SERIES_ID}", ApiVersion::V3.base_path());
+
+    let mock = mock_status_code_at_path(
+        &mut server,
+        path.as_str(),
+        Method::GET,
+        StatusCode::NOT_FOUND,
+    )
+    .await
+    .expect(2);
+
+    let mut tmdb = Tmdb::default();
+    tmdb.override_api_url(server.url().as_str()).unwrap();
+
+    let _a = tv::get(&tmdb, SERIES_ID).await;
+    let _b = tv::get(&tmdb, SERIES_ID).await;
+
+    mock.assert()
+}